@@ -0,0 +1,128 @@
+//! An example backend for a C dialect which can not be parsed by clang. Its
+//! files end with `.cx`, functions may be marked with `export` or `task` and
+//! the test descriptions are written in `%%` comments.
+//!
+//! Run it with `cargo run --example custom_backend`.
+
+extern crate failure;
+extern crate regex;
+extern crate thinlinelib;
+
+use failure::Fallible;
+use regex::Regex;
+use std::{fs::read_to_string, path::Path, process::exit};
+use thinlinelib::{
+    analysis::{Argument, Description, Function, ProjectFile}, entity::{Entity, EntityType},
+    language_type::LanguageBackend, Thinline,
+};
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Matches function definitions like `export int add(int a, int b) {`.
+static FUNCTION: &str = concat!(
+    r"^(?:export\s+|task\s+)?(?P<return_type>[A-Za-z_][\w\s\*]*?[\s\*])",
+    r"(?P<name>[A-Za-z_]\w*)\s*\((?P<arguments>[^)]*)\)"
+);
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug)]
+struct CxBackend {
+    function: Regex,
+}
+
+impl CxBackend {
+    fn new() -> Self {
+        Self {
+            function: Regex::new(FUNCTION).unwrap(),
+        }
+    }
+
+    /// Splits an argument list like `int a, int b` into its arguments.
+    fn arguments(arguments: &str) -> Vec<Argument> {
+        arguments
+            .split(',')
+            .map(str::trim)
+            .filter(|argument| !argument.is_empty() && *argument != "void")
+            .map(|argument| {
+                match argument.rfind(|c: char| c.is_whitespace() || c == '*') {
+                    Some(pos) => {
+                        Argument::new(argument[pos + 1..].trim(), Some(argument[..=pos].trim()))
+                    }
+                    None => Argument::new(argument, None),
+                }
+            })
+            .collect()
+    }
+}
+
+impl LanguageBackend for CxBackend {
+    fn name(&self) -> &str {
+        "cx"
+    }
+
+    fn file_types(&self) -> &[&str] {
+        &["cx"]
+    }
+
+    fn extract_entities(
+        &self,
+        project_file: &ProjectFile,
+        _flags: &[String],
+    ) -> Fallible<Vec<Entity>> {
+        let mut index = Entity::new("");
+        let mut comment: Vec<&str> = Vec::new();
+
+        let content = read_to_string(&project_file.path)?;
+        for line in content.lines() {
+            if line.trim_start().starts_with("%%") {
+                comment.push(line);
+                continue;
+            }
+
+            if let Some(captures) = self.function.captures(line) {
+                let mut function = Function::new(&captures["name"]);
+                function.set_return_type(captures["return_type"].trim())?;
+                function.set_arguments(&Self::arguments(&captures["arguments"]));
+                if !comment.is_empty() {
+                    function.description = Some(self.extract_description(&comment.join("\n")));
+                }
+
+                index.add_entity::<Function>(EntityType::Function(function));
+            }
+
+            comment.clear();
+        }
+
+        Ok(vec![index])
+    }
+
+    fn extract_description(&self, comment: &str) -> Description {
+        let mut description = Description::new();
+        description.set(&comment.replace("%%", ""));
+
+        description
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+fn run() -> Fallible<()> {
+    let mut thinline = Thinline::new(Path::new("examples").join("cx_project"));
+    thinline.register_backend(Box::new(CxBackend::new()));
+    thinline.analyze("thinline.yml", false)?;
+
+    println!("{}", thinline.entity_tree());
+    for (project_file, function, test_case) in thinline.test_cases() {
+        println!("{}: {}: {}", project_file.display(), function, test_case);
+    }
+
+    Ok(())
+}
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("{}", err);
+        exit(1);
+    }
+}
@@ -1,14 +1,48 @@
-use entity::Entity;
+use cache::AnalysisCache;
+use changes;
+use entity::{Access, Entity, EntityType};
 use failure::{err_msg, Fallible};
-use language_type::LanguageType;
+use language_type::{BackendRegistry, LanguageBackend};
+use rayon::{prelude::*, ThreadPoolBuilder};
+use regex::Regex;
 use std::{
-    cell::{Ref, RefCell, RefMut}, ffi::OsStr, fmt::{Display, Formatter, Result}, fs::read_link,
-    marker::PhantomData, path::PathBuf,
+    collections::HashMap, ffi::OsStr, fmt::{Display, Formatter, Result}, fs::{read, read_link},
+    path::{Path, PathBuf},
+    sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard},
 };
 use walkdir::WalkDir;
 
 ////////////////////////////////////////////////////////////////////////////////
 
+lazy_static! {
+    static ref TYPE_SPACES_BEFORE: Regex = Regex::new(r"\s+([*&,<>)\]])").unwrap();
+    static ref TYPE_SPACES_AFTER: Regex = Regex::new(r"([<(\[])\s+").unwrap();
+    static ref TYPE_SPACES: Regex = Regex::new(r"\s+").unwrap();
+    static ref TYPE_COMMA: Regex = Regex::new(r",(\S)").unwrap();
+}
+
+/// The file extensions of headers which are included by the generated tests.
+pub static HEADER_FILE_EXTENSIONS: &[&str] = &["h", "hpp", "hh", "hxx"];
+
+/// Normalizes the spelling of a type, so that equal types are spelled equally.
+///
+/// # Example
+///
+/// ```
+/// use thinlinelib::analysis::normalize_type;
+///
+/// assert_eq!(normalize_type("const  int * const"), "const int* const");
+/// assert_eq!(normalize_type("std::map< int ,double > &"), "std::map<int, double>&");
+/// ```
+pub fn normalize_type(atype: &str) -> String {
+    let atype = TYPE_SPACES.replace_all(atype.trim(), " ");
+    let atype = TYPE_SPACES_BEFORE.replace_all(&atype, "$1");
+    let atype = TYPE_SPACES_AFTER.replace_all(&atype, "$1");
+    TYPE_COMMA.replace_all(&atype, ", $1").into_owned()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
 /// Represents a parsed entity description.
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Description {
@@ -68,12 +102,37 @@ impl Description {
 
 ////////////////////////////////////////////////////////////////////////////////
 
-/// Represents a parsed function argument.
+/// The kinds of function arguments.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ArgumentKind {
+    /// A single argument.
+    Single,
+
+    /// The variadic arguments of C and C++ (`...`) or Python (`*args`).
+    Variadic,
+
+    /// A Python argument following `*` or `*args`, which is only passed by keyword.
+    KeywordOnly,
+
+    /// The variadic keyword arguments of Python (`**kwargs`).
+    Keywords,
+}
+
+impl Default for ArgumentKind {
+    fn default() -> Self {
+        ArgumentKind::Single
+    }
+}
+
+/// Represents a parsed function argument. The value of an argument of a
+/// function is its default value, while the value of an argument of a
+/// `TL_FCT` call is the passed one.
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Argument {
     pub name: String,
     pub atype: Option<String>,
     pub value: Option<String>,
+    pub kind: ArgumentKind,
 }
 
 impl Argument {
@@ -95,6 +154,28 @@ impl Argument {
             name: name.into(),
             atype: atype.map(S::into),
             value: None,
+            kind: ArgumentKind::Single,
+        }
+    }
+
+    /// Creates a new Argument instance taking a variable number of values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use thinlinelib::analysis::{Argument, ArgumentKind};
+    ///
+    /// let argument = Argument::variadic("...", ArgumentKind::Variadic);
+    ///
+    /// assert!(argument.is_optional());
+    /// assert_eq!(argument.to_string(), "...");
+    /// ```
+    pub fn variadic<S: Into<String>>(name: S, kind: ArgumentKind) -> Self {
+        Self {
+            name: name.into(),
+            atype: None,
+            value: None,
+            kind,
         }
     }
 
@@ -114,6 +195,123 @@ impl Argument {
     pub fn set_value(&mut self, value: &str) {
         self.value = Some(String::from(value));
     }
+
+    /// Formats the Argument as Python parameter, e.g. `factor: float = 1.5`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use thinlinelib::analysis::{Argument, ArgumentKind};
+    ///
+    /// let mut argument = Argument::new("factor", Some("float"));
+    /// argument.set_value("1.5");
+    /// assert_eq!(argument.to_python_string(), "factor: float = 1.5");
+    ///
+    /// let mut argument = Argument::new("options", None);
+    /// argument.kind = ArgumentKind::Keywords;
+    /// assert_eq!(argument.to_python_string(), "**options");
+    /// ```
+    pub fn to_python_string(&self) -> String {
+        let prefix = match self.kind {
+            ArgumentKind::Variadic => "*",
+            ArgumentKind::Keywords => "**",
+            _ => "",
+        };
+
+        match (&self.atype, &self.value) {
+            (Some(atype), Some(value)) => format!("{}{}: {} = {}", prefix, self.name, atype, value),
+            (Some(atype), None) => format!("{}{}: {}", prefix, self.name, atype),
+            (None, Some(value)) => format!("{}{}={}", prefix, self.name, value),
+            (None, None) => format!("{}{}", prefix, self.name),
+        }
+    }
+
+    /// Returns whether the argument of a function can be omitted when calling
+    /// it, which is the case for defaulted and variadic arguments.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use thinlinelib::analysis::Argument;
+    ///
+    /// let mut argument = Argument::new("no2", Some("int"));
+    /// assert!(!argument.is_optional());
+    ///
+    /// argument.set_value("5");
+    /// assert!(argument.is_optional());
+    /// ```
+    pub fn is_optional(&self) -> bool {
+        self.value.is_some()
+            || self.kind == ArgumentKind::Variadic
+            || self.kind == ArgumentKind::Keywords
+    }
+}
+
+impl Display for Argument {
+    /// Formats an Argument to be displayed by std output.
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        if let Some(atype) = &self.atype {
+            write!(f, "{} ", atype)?;
+        }
+        match self.kind {
+            ArgumentKind::Variadic if self.name != "..." => write!(f, "*")?,
+            ArgumentKind::Keywords => write!(f, "**")?,
+            _ => {}
+        }
+        write!(f, "{}", self.name)?;
+        if let Some(value) = &self.value {
+            write!(f, " = {}", value)?;
+        }
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// A position within a source file.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Location {
+    pub path: PathBuf,
+    pub line: u32,
+    pub column: u32,
+}
+
+impl Display for Location {
+    /// Formats a Location like `src/src1.c:8:5`.
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "{}:{}:{}", self.path.display(), self.line, self.column)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// The storage classes a function can be declared with.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum StorageClass {
+    Static,
+    Extern,
+}
+
+/// The linkage of a function, which tells whether it can be referred to
+/// from other translation units.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Linkage {
+    Internal,
+    External,
+}
+
+/// The qualifiers and specifiers a function can be declared with.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Qualifier {
+    Inline,
+    Virtual,
+    PureVirtual,
+    Const,
+    Noexcept,
+    Constexpr,
+    Deleted,
+    Defaulted,
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -122,9 +320,23 @@ impl Argument {
 #[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Function {
     pub name: String,
+    pub qualified_name: String,
+    pub usr: Option<String>,
+    pub access: Option<Access>,
+    pub storage_class: Option<StorageClass>,
+    pub linkage: Option<Linkage>,
+    pub qualifiers: Vec<Qualifier>,
+    pub template_parameters: Vec<String>,
     pub return_type: Option<String>,
     pub arguments: Vec<Argument>,
     pub description: Option<Description>,
+    pub declaration: Option<Location>,
+    pub definition: Option<Location>,
+    pub decorators: Vec<String>,
+    pub is_async: bool,
+    /// The language the Function is written in, when its signature is
+    /// displayed other than in C style (e.g. `python`).
+    pub language: Option<String>,
 }
 
 impl Function {
@@ -143,11 +355,25 @@ impl Function {
     /// assert!(function.description.is_none());
     /// ```
     pub fn new<S: Into<String>>(name: S) -> Self {
+        let name = name.into();
+
         Self {
-            name: name.into(),
+            qualified_name: name.clone(),
+            name,
+            usr: None,
+            access: None,
+            storage_class: None,
+            linkage: None,
+            qualifiers: Vec::new(),
+            template_parameters: Vec::new(),
             return_type: None,
             arguments: Vec::new(),
             description: None,
+            declaration: None,
+            definition: None,
+            decorators: Vec::new(),
+            is_async: false,
+            language: None,
         }
     }
 
@@ -215,6 +441,141 @@ impl Function {
     pub fn set_arguments(&mut self, arguments: &[Argument]) {
         self.arguments = arguments.into();
     }
+
+    /// Returns why the Function can not be called from a test, or `None`
+    /// when it can be tested.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    /// use thinlinelib::analysis::{Function, Linkage, Location, Qualifier};
+    ///
+    /// let location = |path: &str| Location {
+    ///     path: PathBuf::from(path),
+    ///     line: 3,
+    ///     column: 12,
+    /// };
+    ///
+    /// let mut function = Function::new("helper");
+    /// assert!(function.untestable_reason().is_none());
+    ///
+    /// // A static function of a header is visible to the tests including it.
+    /// function.linkage = Some(Linkage::Internal);
+    /// function.declaration = Some(location("include/helper.h"));
+    /// assert!(function.untestable_reason().is_none());
+    ///
+    /// function.declaration = Some(location("src/helper.c"));
+    /// assert!(function.untestable_reason().is_some());
+    ///
+    /// let mut function = Function::new("operator=");
+    /// function.qualifiers.push(Qualifier::Deleted);
+    /// assert!(function.untestable_reason().is_some());
+    /// ```
+    pub fn untestable_reason(&self) -> Option<&'static str> {
+        if self.qualifiers.contains(&Qualifier::Deleted) {
+            return Some("it is deleted");
+        }
+
+        // Functions with internal linkage can only be called by the tests
+        // when they are declared within a header the tests include.
+        if self.linkage == Some(Linkage::Internal) {
+            let is_declared_in_source = self
+                .declaration
+                .as_ref()
+                .or_else(|| self.definition.as_ref())
+                .and_then(|location| location.path.extension())
+                .and_then(OsStr::to_str)
+                .map_or(false, |ext| !HEADER_FILE_EXTENSIONS.contains(&ext));
+            if is_declared_in_source {
+                return Some("it has internal linkage");
+            }
+        }
+
+        None
+    }
+
+    /// Returns the arguments formatted as Python parameters. Keyword-only
+    /// arguments without preceding `*args` are separated by a bare `*`.
+    fn python_arguments(&self) -> Vec<String> {
+        let mut arguments = Vec::new();
+        let mut has_separator = false;
+        for argument in &self.arguments {
+            match argument.kind {
+                ArgumentKind::Variadic => has_separator = true,
+                ArgumentKind::KeywordOnly if !has_separator => {
+                    arguments.push(String::from("*"));
+                    has_separator = true;
+                }
+                _ => {}
+            }
+            arguments.push(argument.to_python_string());
+        }
+
+        arguments
+    }
+
+    /// Returns the normalized signature of the Function, which tells apart
+    /// the overloads of a function.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use thinlinelib::analysis::{Argument, Function};
+    ///
+    /// let mut function = Function::new("add");
+    /// function.qualified_name = String::from("ns1::add");
+    /// function.set_arguments(&vec![
+    ///     Argument::new("no1", Some("const int  *")),
+    ///     Argument::new("no2", Some("double")),
+    /// ]);
+    ///
+    /// assert_eq!(function.signature(), "ns1::add(const int*, double)");
+    /// ```
+    pub fn signature(&self) -> String {
+        let argument_types = self
+            .arguments
+            .iter()
+            .map(|argument| {
+                argument
+                    .atype
+                    .as_ref()
+                    .map_or_else(|| argument.name.clone(), |atype| normalize_type(atype))
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        format!("{}({})", self.qualified_name, argument_types)
+    }
+}
+
+impl Display for Function {
+    /// Formats a Function to be displayed by std output.
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let arguments = match self.language.as_ref().map(String::as_str) {
+            Some("python") => self.python_arguments(),
+            _ => self
+                .arguments
+                .iter()
+                .map(|argument| argument.to_string())
+                .collect::<Vec<String>>(),
+        };
+        let arguments = arguments.join(", ");
+        if self.is_async {
+            write!(f, "async ")?;
+        }
+        write!(f, "{}", self.name)?;
+        if !self.template_parameters.is_empty() {
+            write!(f, "<{}>", self.template_parameters.join(", "))?;
+        }
+        write!(f, "({})", arguments)?;
+
+        if let Some(return_type) = &self.return_type {
+            write!(f, " -> {}", return_type)?;
+        }
+
+        Ok(())
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -223,6 +584,7 @@ impl Function {
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Enum {
     pub name: String,
+    pub qualified_name: String,
     pub etype: Option<String>,
     pub arguments: Vec<Argument>,
 }
@@ -242,8 +604,11 @@ impl Enum {
     /// assert!(enumeration.arguments.is_empty());
     /// ```
     pub fn new<S: Into<String>>(name: S) -> Self {
+        let name = name.into();
+
         Self {
-            name: name.into(),
+            qualified_name: name.clone(),
+            name,
             etype: None,
             arguments: Vec::new(),
         }
@@ -269,18 +634,35 @@ impl Enum {
 
 ////////////////////////////////////////////////////////////////////////////////
 
+/// The path a project file is imported with, e.g. by the synthesized tests
+/// of languages like Python.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ModulePath {
+    /// The directory the module is imported relative to.
+    pub root: PathBuf,
+
+    /// The dotted path of the module, e.g. `package.module`.
+    pub name: String,
+}
+
+impl Display for ModulePath {
+    /// Formats a ModulePath to be displayed by std output.
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
 /// Represents a parsed project file.
-#[derive(Default, Clone, Debug)]
-pub struct ProjectFile<T> {
+#[derive(Default, Debug)]
+pub struct ProjectFile {
     pub path: PathBuf,
-    pub entities: RefCell<Vec<Entity>>,
-    pub pf_type: PhantomData<T>,
+    pub module_path: Option<ModulePath>,
+    pub entities: RwLock<Vec<Entity>>,
 }
 
-impl<T> ProjectFile<T>
-where
-    T: LanguageType,
-{
+impl ProjectFile {
     /// Creates a new ProjectFile instance.
     ///
     /// # Example
@@ -288,18 +670,18 @@ where
     /// ```
     /// use std::path::PathBuf;
     /// use thinlinelib::analysis::ProjectFile;
-    /// use thinlinelib::language_type::C;
     ///
-    /// let project_file: ProjectFile<C> = ProjectFile::new("test/project_file");
+    /// let project_file = ProjectFile::new("test/project_file");
     ///
     /// assert_eq!(project_file.path, PathBuf::from("test/project_file"));
+    /// assert!(project_file.module_path.is_none());
     /// assert_eq!(project_file.entities().len(), 0);
     /// ```
     pub fn new<S: Into<PathBuf>>(path: S) -> Self {
         Self {
             path: path.into(),
-            entities: RefCell::new(Vec::new()),
-            pf_type: PhantomData,
+            module_path: None,
+            entities: RwLock::new(Vec::new()),
         }
     }
 
@@ -310,15 +692,14 @@ where
     /// ```
     /// use thinlinelib::analysis::ProjectFile;
     /// use thinlinelib::entity::Entity;
-    /// use thinlinelib::language_type::C;
     ///
-    /// let project_file: ProjectFile<C> = ProjectFile::new("test/project_file");
+    /// let project_file = ProjectFile::new("test/project_file");
     /// project_file.entities_mut().push(Entity::new("testEntity"));
     ///
     /// assert_eq!(project_file.entities().len(), 1);
     /// ```
-    pub fn entities(&self) -> Ref<Vec<Entity>> {
-        self.entities.borrow()
+    pub fn entities(&self) -> RwLockReadGuard<'_, Vec<Entity>> {
+        self.entities.read().unwrap_or_else(PoisonError::into_inner)
     }
 
     /// Returns a mutable reference to the entities list.
@@ -328,9 +709,8 @@ where
     /// ```
     /// use thinlinelib::analysis::ProjectFile;
     /// use thinlinelib::entity::Entity;
-    /// use thinlinelib::language_type::C;
     ///
-    /// let project_file: ProjectFile<C> = ProjectFile::new("test/project_file");
+    /// let project_file = ProjectFile::new("test/project_file");
     /// project_file.entities_mut().push(Entity::new("testEntity"));
     ///
     /// let mut entities = project_file.entities_mut();
@@ -339,15 +719,12 @@ where
     /// entities.clear();
     /// assert_eq!(entities.len(), 0);
     /// ```
-    pub fn entities_mut(&self) -> RefMut<Vec<Entity>> {
-        self.entities.borrow_mut()
+    pub fn entities_mut(&self) -> RwLockWriteGuard<'_, Vec<Entity>> {
+        self.entities.write().unwrap_or_else(PoisonError::into_inner)
     }
 }
 
-impl<T> Display for ProjectFile<T>
-where
-    T: LanguageType,
-{
+impl Display for ProjectFile {
     /// Formats a ProjectFile to be displayed by std output.
     fn fmt(&self, f: &mut Formatter) -> Result {
         if let Some(path) = self.path.to_str() {
@@ -359,43 +736,279 @@ where
 
 ////////////////////////////////////////////////////////////////////////////////
 
+/// The declarations of a function within all project files, which are
+/// merged into one of them.
+struct Declarations {
+    /// The index of the declaration which is kept.
+    kept: usize,
+
+    /// Whether the kept declaration is located within its own project file
+    /// and whether it carries a description.
+    priority: (bool, bool),
+
+    description: Option<Description>,
+    declaration: Option<Location>,
+    definition: Option<Location>,
+}
+
+/// Returns whether the `Function` is located within the given project file,
+/// rather than within a header included by it.
+fn is_located_in(function: &Function, path: &Path) -> bool {
+    let canonical = |path: &Path| path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+
+    function
+        .declaration
+        .as_ref()
+        .or_else(|| function.definition.as_ref())
+        .map_or(false, |location| canonical(&location.path) == canonical(path))
+}
+
+/// Collects the declarations of the functions of an `Entity` and all of its
+/// children by their USR. The functions are numbered by `index` in the order
+/// `merge_function_declarations` visits them.
+fn collect_function_declarations(
+    entity: &Entity,
+    path: &Path,
+    index: &mut usize,
+    declarations: &mut HashMap<String, Declarations>,
+) {
+    for function in entity.functions() {
+        if let Some(usr) = &function.usr {
+            let priority = (is_located_in(function, path), function.description.is_some());
+            let merged = declarations
+                .entry(usr.clone())
+                .or_insert_with(|| Declarations {
+                    kept: *index,
+                    priority,
+                    description: None,
+                    declaration: None,
+                    definition: None,
+                });
+
+            if priority > merged.priority {
+                merged.kept = *index;
+                merged.priority = priority;
+            }
+            if merged.description.is_none() {
+                merged.description = function.description.clone();
+            }
+            if merged.declaration.is_none() {
+                merged.declaration = function.declaration.clone();
+            }
+            if merged.definition.is_none() {
+                merged.definition = function.definition.clone();
+            }
+        }
+        *index += 1;
+    }
+
+    for child in &entity.entities {
+        if let EntityType::Entity(child) = child {
+            collect_function_declarations(child, path, index, declarations);
+        }
+    }
+}
+
+/// Removes the functions from an `Entity` and all of its children which are
+/// not the kept declaration of their USR, and completes the kept ones.
+fn merge_function_declarations(
+    entity: &mut Entity,
+    index: &mut usize,
+    declarations: &HashMap<String, Declarations>,
+) {
+    entity.entities.retain(|child| match child {
+        EntityType::Function(function) => {
+            let is_kept = function
+                .usr
+                .as_ref()
+                .and_then(|usr| declarations.get(usr))
+                .map_or(true, |merged| merged.kept == *index);
+            *index += 1;
+            is_kept
+        }
+        _ => true,
+    });
+
+    for child in &mut entity.entities {
+        match child {
+            EntityType::Function(function) => {
+                if let Some(merged) = function.usr.as_ref().and_then(|usr| declarations.get(usr)) {
+                    if function.description.is_none() {
+                        function.description = merged.description.clone();
+                    }
+                    function.declaration = merged.declaration.clone();
+                    function.definition = merged.definition.clone();
+                }
+            }
+            EntityType::Entity(child) => merge_function_declarations(child, index, declarations),
+            _ => {}
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Tells how the analysis handles project files which fail to be analyzed,
+/// e.g. because of a syntax error.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Strictness {
+    /// The first failed file fails the analysis.
+    Strict,
+
+    /// Failed files are reported as warning and skipped.
+    Warn,
+
+    /// Failed files are skipped silently.
+    Ignore,
+}
+
+impl Default for Strictness {
+    fn default() -> Self {
+        Strictness::Warn
+    }
+}
+
+impl Strictness {
+    /// Returns the `Strictness` connected to the given name.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use thinlinelib::analysis::Strictness;
+    ///
+    /// assert_eq!(Strictness::from_name("strict").unwrap(), Strictness::Strict);
+    /// assert_eq!(Strictness::from_name("warn").unwrap(), Strictness::Warn);
+    /// assert_eq!(Strictness::from_name("ignore").unwrap(), Strictness::Ignore);
+    /// assert!(Strictness::from_name("lenient").is_err());
+    /// ```
+    pub fn from_name(name: &str) -> Fallible<Self> {
+        match name {
+            "strict" => Ok(Strictness::Strict),
+            "warn" => Ok(Strictness::Warn),
+            "ignore" => Ok(Strictness::Ignore),
+            _ => Err(format_err!("Unknown strictness '{}'.", name)),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
 /// The analyzer which fulfills parsing and entity extraction tasks.
 #[derive(Default, Debug)]
-pub struct Analysis<T>
-where
-    T: LanguageType,
-{
-    pub file_types: &'static [&'static str],
-    project_files: RefCell<Vec<ProjectFile<T>>>,
+pub struct Analysis {
+    /// The languages whose project files are analyzed.
+    pub languages: Vec<String>,
+
+    /// The number of project files analyzed in parallel, where `0` means
+    /// one per logical CPU.
+    pub jobs: usize,
+
+    /// How project files which fail to be analyzed are handled.
+    pub strictness: Strictness,
+
+    /// The flags passed to the language backends, e.g. include directories.
+    pub flags: Vec<String>,
+
+    /// The directory modules are imported relative to. Without it, the
+    /// backends derive it from the location of the project files, e.g.
+    /// from the packages containing them.
+    pub module_root: Option<PathBuf>,
+
+    backends: BackendRegistry,
+    cache: Option<RwLock<AnalysisCache>>,
+    project_files: RwLock<Vec<ProjectFile>>,
 }
 
-impl<T> Analysis<T>
-where
-    T: LanguageType,
-{
-    /// Creates a new Analysis instance.
+impl Analysis {
+    /// Creates a new Analysis instance for the given languages.
     ///
     /// # Example
     ///
     /// ```
     /// use thinlinelib::analysis::Analysis;
-    /// use thinlinelib::language_type::{C, LanguageType};
     ///
-    /// let analysis: Analysis<C> = Analysis::new();
+    /// let analysis = Analysis::new(&["c", "cpp"]);
     ///
-    /// assert_eq!(analysis.file_types, C::file_types());
+    /// assert_eq!(analysis.languages, vec!["c", "cpp"]);
     /// assert_eq!(analysis.project_files().len(), 0);
     /// ```
-    pub fn new() -> Self {
+    pub fn new<S: AsRef<str>>(languages: &[S]) -> Self {
         Self {
-            file_types: T::file_types(),
-            project_files: RefCell::new(Vec::new()),
+            languages: languages
+                .iter()
+                .map(|language| String::from(language.as_ref()))
+                .collect(),
+            jobs: 0,
+            strictness: Strictness::default(),
+            flags: Vec::new(),
+            module_root: None,
+            backends: BackendRegistry::new(),
+            cache: None,
+            project_files: RwLock::new(Vec::new()),
         }
     }
 
+    /// Returns a reference to the registered language backends.
+    pub fn backends(&self) -> &BackendRegistry {
+        &self.backends
+    }
+
+    /// Returns a mutable reference to the registered language backends.
+    pub fn backends_mut(&mut self) -> &mut BackendRegistry {
+        &mut self.backends
+    }
+
+    /// Sets the `AnalysisCache` whose entities are taken for unchanged files.
+    pub fn set_cache(&mut self, cache: AnalysisCache) {
+        self.cache = Some(RwLock::new(cache));
+    }
+
+    /// Takes the `AnalysisCache`, updated with the entities of the analyzed files.
+    pub fn take_cache(&mut self) -> Option<AnalysisCache> {
+        self.cache
+            .take()
+            .map(|cache| cache.into_inner().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    /// Returns the backend of the analyzed languages which is responsible
+    /// for the given path.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::path::Path;
+    /// use thinlinelib::analysis::Analysis;
+    ///
+    /// let analysis = Analysis::new(&["c", "python"]);
+    ///
+    /// assert_eq!(analysis.backend(Path::new("src/src1.py")).unwrap().name(), "python");
+    /// assert!(analysis.backend(Path::new("src/src1.cpp")).is_none());
+    /// ```
+    pub fn backend(&self, path: &Path) -> Option<&dyn LanguageBackend> {
+        self.backends.for_path(path, &self.languages)
+    }
+
     /// Returns a reference to the collected project files for analysis.
-    pub fn project_files(&self) -> Ref<Vec<ProjectFile<T>>> {
-        self.project_files.borrow()
+    pub fn project_files(&self) -> RwLockReadGuard<'_, Vec<ProjectFile>> {
+        self.project_files
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns the entity trees extracted from the given project file, or
+    /// `None` when the file was not analyzed.
+    pub fn file_entities(&self, path: &Path) -> Option<Vec<Entity>> {
+        let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+
+        self.project_files()
+            .iter()
+            .find(|project_file| {
+                project_file
+                    .path
+                    .canonicalize()
+                    .map_or(project_file.path == path, |project_path| project_path == path)
+            })
+            .map(|project_file| project_file.entities().clone())
     }
 
     /// Returns a mutable reference to the collected project files for analysis.
@@ -404,17 +1017,18 @@ where
     ///
     /// ```
     /// use thinlinelib::analysis::{Analysis, ProjectFile};
-    /// use thinlinelib::language_type::C;
     ///
-    /// let analysis: Analysis<C> = Analysis::new();
+    /// let analysis = Analysis::new(&["c"]);
     /// let mut project_files = analysis.project_files_mut();
     /// assert_eq!(project_files.len(), 0);
     ///
     /// project_files.push(ProjectFile::new("test/anotherFile"));
     /// assert_eq!(project_files.len(), 1);
     /// ```
-    pub fn project_files_mut(&self) -> RefMut<Vec<ProjectFile<T>>> {
-        self.project_files.borrow_mut()
+    pub fn project_files_mut(&self) -> RwLockWriteGuard<'_, Vec<ProjectFile>> {
+        self.project_files
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
     }
 
     /// Collects all the sources within the given project dir.
@@ -433,19 +1047,35 @@ where
         }
 
         // Traverse through the files within the specified source directories
-        // and store them for analyzing purposes
+        // and store the ones of the analyzed languages for analyzing purposes
         for src_dir in search_dirs {
-            for ext in self.file_types {
-                for dir in WalkDir::new(project_dir.join(src_dir).to_str().unwrap_or(".")) {
-                    let entry = dir?;
-                    if !entry.path().is_dir() && entry.path().extension() == Some(OsStr::new(ext)) {
-                        let file = if entry.path_is_symlink() {
-                            ProjectFile::new(read_link(entry.path())?)
-                        } else {
-                            ProjectFile::new(entry.path())
-                        };
-                        debug!("Add target project file '{}' to analysis.", file);
-                        self.project_files_mut().push(file);
+            for language in &self.languages {
+                let backend = self
+                    .backends
+                    .by_name(language)
+                    .ok_or_else(|| format_err!("Unsupported language '{}'.", language))?;
+
+                for ext in self.backends.file_types(backend.name()) {
+                    // The files are sorted to get the same analysis result on every run.
+                    let walk_dir = WalkDir::new(project_dir.join(src_dir).to_str().unwrap_or("."))
+                        .sort_by(|a, b| a.file_name().cmp(b.file_name()));
+                    for dir in walk_dir {
+                        let entry = dir?;
+
+                        // Files claimed by several languages are collected only once.
+                        let is_responsible = self
+                            .backend(entry.path())
+                            .map_or(false, |responsible| responsible.name() == backend.name());
+                        if !entry.path().is_dir()
+                            && entry.path().extension() == Some(OsStr::new(ext))
+                            && is_responsible
+                        {
+                            if entry.path_is_symlink() {
+                                self.add_project_file(read_link(entry.path())?);
+                            } else {
+                                self.add_project_file(entry.path());
+                            }
+                        }
                     }
                 }
             }
@@ -454,8 +1084,175 @@ where
         Ok(())
     }
 
-    /// Extracts function signatures and comments of thinlines parsed files.
+    /// Adds the file at the given path to the analyzed project files, together
+    /// with its module path determined by the responsible backend.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::path::Path;
+    /// use thinlinelib::analysis::Analysis;
+    ///
+    /// let analysis = Analysis::new(&["python"]);
+    /// analysis.add_project_file(Path::new("examples").join("python_project").join("src1.py"));
+    ///
+    /// let project_files = analysis.project_files();
+    /// assert_eq!(project_files[0].module_path.as_ref().unwrap().name, "src1");
+    /// ```
+    pub fn add_project_file<P: Into<PathBuf>>(&self, path: P) {
+        let path = path.into();
+        let module_root = self.module_root.as_ref().map(PathBuf::as_path);
+        let module_path = self
+            .backend(&path)
+            .and_then(|backend| backend.module_path(&path, module_root));
+
+        let mut file = ProjectFile::new(path);
+        file.module_path = module_path;
+
+        debug!("Add target project file '{}' to analysis.", file);
+        self.project_files_mut().push(file);
+    }
+
+    /// Returns the content of the given project file followed by the contents
+    /// of the files it includes from the project or the include directories.
+    fn contents(&self, project_file: &ProjectFile) -> Fallible<Vec<Vec<u8>>> {
+        let include_dirs: Vec<PathBuf> = self
+            .flags
+            .iter()
+            .filter(|flag| flag.starts_with("-I"))
+            .map(|flag| PathBuf::from(&flag[2..]))
+            .collect();
+
+        let mut contents = vec![read(&project_file.path)?];
+        for included_file in changes::included_files(&project_file.path, &include_dirs) {
+            contents.push(read(included_file)?);
+        }
+
+        Ok(contents)
+    }
+
+    /// Extracts the entities of a single project file with the given backend.
+    /// When a cache is set, the entities of unchanged files are taken from it.
+    fn extract_file_entities(
+        &self,
+        backend: &dyn LanguageBackend,
+        project_file: &ProjectFile,
+    ) -> Fallible<Vec<Entity>> {
+        if let Some(cache) = &self.cache {
+            let contents = self.contents(project_file)?;
+            let hash = AnalysisCache::hash(backend.name(), &contents, &self.flags);
+
+            let cached_entities = cache
+                .read()
+                .unwrap_or_else(PoisonError::into_inner)
+                .get(&project_file.path, &hash)
+                .cloned();
+            if let Some(entities) = cached_entities {
+                debug!("Using cached entities of '{}'", project_file);
+                return Ok(entities);
+            }
+
+            let entities = self.analyze_file(backend, project_file)?;
+            cache
+                .write()
+                .unwrap_or_else(PoisonError::into_inner)
+                .insert(&project_file.path, hash, entities.clone());
+
+            return Ok(entities);
+        }
+
+        self.analyze_file(backend, project_file)
+    }
+
+    /// Extracts the entities of the given project file with the given
+    /// backend and sets their qualified names.
+    fn analyze_file(
+        &self,
+        backend: &dyn LanguageBackend,
+        project_file: &ProjectFile,
+    ) -> Fallible<Vec<Entity>> {
+        info!("Analyzing '{}'", project_file);
+
+        let mut entities = backend.extract_entities(project_file, &self.flags)?;
+        for entity in &mut entities {
+            entity.qualify_with(backend.scope_separator());
+        }
+
+        Ok(entities)
+    }
+
+    /// Extracts function signatures and comments of thinlines parsed files
+    /// with the backend responsible for each file. The files are analyzed
+    /// in parallel by `jobs` threads, while the project files keep their order.
+    /// Depending on the `strictness`, files which fail to be analyzed either
+    /// fail the analysis or are skipped, while the remaining files are kept.
     pub fn extract_entities(&self) -> Fallible<()> {
-        T::extract_entities(&self)
+        let thread_pool = ThreadPoolBuilder::new()
+            .num_threads(self.jobs)
+            .build()
+            .map_err(|err| format_err!("Unable to create the analysis threads: {}", err))?;
+
+        let project_files = self.project_files();
+        let extracted_entities: Vec<Fallible<Vec<Entity>>> = thread_pool.install(|| {
+            project_files
+                .par_iter()
+                .map(|project_file| {
+                    let backend = self.backend(&project_file.path).ok_or_else(|| {
+                        format_err!("No language backend found for '{}'.", project_file)
+                    })?;
+
+                    self.extract_file_entities(backend, project_file)
+                })
+                .collect()
+        });
+
+        // In strict mode the first failed file in order of the project files
+        // fails the analysis.
+        for (project_file, entities) in project_files.iter().zip(extracted_entities) {
+            let entities = match (entities, &self.strictness) {
+                (Ok(entities), _) => entities,
+                (Err(err), Strictness::Strict) => return Err(err),
+                (Err(err), Strictness::Warn) => {
+                    warn!("Skipping '{}': {}", project_file, err);
+                    continue;
+                }
+                (Err(err), Strictness::Ignore) => {
+                    debug!("Skipping '{}': {}", project_file, err);
+                    continue;
+                }
+            };
+            debug!("{:#?}", entities);
+            project_file.entities_mut().extend(entities);
+        }
+
+        Self::merge_declarations(&project_files);
+
+        Ok(())
+    }
+
+    /// Merges the declarations and the definition of every function, which
+    /// are found within several project files or within included headers,
+    /// into a single `Function`. The kept one is preferably located within
+    /// its own project file and carries the description.
+    fn merge_declarations(project_files: &[ProjectFile]) {
+        let mut declarations = HashMap::new();
+        let mut index = 0;
+        for project_file in project_files {
+            for entity in project_file.entities().iter() {
+                collect_function_declarations(
+                    entity,
+                    &project_file.path,
+                    &mut index,
+                    &mut declarations,
+                );
+            }
+        }
+
+        let mut index = 0;
+        for project_file in project_files {
+            for entity in project_file.entities_mut().iter_mut() {
+                merge_function_declarations(entity, &mut index, &declarations);
+            }
+        }
     }
 }
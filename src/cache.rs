@@ -0,0 +1,197 @@
+use entity::Entity;
+use failure::Fallible;
+use serde_json;
+use std::{
+    collections::HashMap, fs::{create_dir_all, read_to_string, File}, io::Write, path::Path,
+};
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// The name of the cache file within the output directory.
+pub static CACHE_FILE_NAME: &str = "analysis_cache.json";
+
+/// The version of the cached entity trees, which are discarded when thinline
+/// is updated.
+static CACHE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The offset basis of the 64 bit FNV-1a hash.
+static FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// The prime of the 64 bit FNV-1a hash.
+static FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// A 64 bit FNV-1a hash. Unlike the `DefaultHasher` its result is stable
+/// across Rust releases, which is required for hashes persisted on disk.
+pub(crate) struct Fnv1a(u64);
+
+impl Fnv1a {
+    pub(crate) fn new() -> Self {
+        Fnv1a(FNV_OFFSET_BASIS)
+    }
+
+    /// Hashes the given bytes, prefixed by their length to keep consecutive
+    /// fields apart.
+    pub(crate) fn write(&mut self, bytes: &[u8]) {
+        let length = bytes.len() as u64;
+        for byte in (0..8).map(|shift| (length >> (shift * 8)) as u8).chain(bytes.iter().cloned()) {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    /// Returns the hash as hexadecimal string.
+    pub(crate) fn finish(&self) -> String {
+        format!("{:016x}", self.0)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// The cached analysis result of a single project file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CacheEntry {
+    /// The hash of the analyzed file content and flags.
+    pub hash: String,
+
+    /// The entity trees extracted from the project file.
+    pub entities: Vec<Entity>,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// A persistent cache of the entity trees extracted from the project files,
+/// which is used to skip the analysis of unchanged files.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnalysisCache {
+    version: String,
+    entries: HashMap<String, CacheEntry>,
+}
+
+impl Default for AnalysisCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AnalysisCache {
+    /// Creates a new, empty `AnalysisCache` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use thinlinelib::cache::AnalysisCache;
+    ///
+    /// let cache = AnalysisCache::new();
+    ///
+    /// assert!(cache.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            version: String::from(CACHE_VERSION),
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Loads the cache from the given file. A missing, unreadable or
+    /// outdated cache file results in an empty cache.
+    pub fn load(path: &Path) -> Self {
+        if !path.is_file() {
+            return Self::new();
+        }
+
+        let cache = read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<Self>(&content).ok());
+        if let Some(cache) = cache {
+            if cache.version == CACHE_VERSION {
+                return cache;
+            }
+        }
+
+        warn!("Discarding the analysis cache '{}'.", path.display());
+        Self::new()
+    }
+
+    /// Saves the cache to the given file. Entries of files which do not
+    /// exist anymore are dropped.
+    pub fn save(&mut self, path: &Path) -> Fallible<()> {
+        self.entries.retain(|project_file, _| Path::new(project_file).is_file());
+
+        if let Some(parent) = path.parent() {
+            create_dir_all(parent)?;
+        }
+        File::create(path)?.write_all(serde_json::to_string(self)?.as_bytes())?;
+
+        Ok(())
+    }
+
+    /// Returns whether the cache contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the hash identifying the analysis of the given file contents
+    /// by the given backend with the given flags. The contents are the ones
+    /// of the project file followed by the ones of the files it includes,
+    /// thus changing an included header invalidates the entry.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use thinlinelib::cache::AnalysisCache;
+    ///
+    /// let source = b"#include \"header.h\"".to_vec();
+    /// let hash = AnalysisCache::hash("c", &[source.clone(), b"int a;".to_vec()], &[]);
+    ///
+    /// assert_eq!(hash, AnalysisCache::hash("c", &[source.clone(), b"int a;".to_vec()], &[]));
+    /// assert_ne!(hash, AnalysisCache::hash("c", &[source, b"int b;".to_vec()], &[]));
+    /// ```
+    pub fn hash(backend: &str, contents: &[Vec<u8>], flags: &[String]) -> String {
+        let mut hasher = Fnv1a::new();
+        hasher.write(backend.as_bytes());
+        hasher.write(contents.len().to_string().as_bytes());
+        for content in contents {
+            hasher.write(content);
+        }
+        for flag in flags {
+            hasher.write(flag.as_bytes());
+        }
+
+        hasher.finish()
+    }
+
+    /// Returns the cached entities of the given project file, if they were
+    /// extracted from a file with the given hash.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::path::Path;
+    /// use thinlinelib::cache::AnalysisCache;
+    /// use thinlinelib::entity::Entity;
+    ///
+    /// let mut cache = AnalysisCache::new();
+    /// let hash = AnalysisCache::hash("c", &[b"int add(int a, int b);".to_vec()], &[]);
+    /// cache.insert(Path::new("src/src1.c"), hash.clone(), vec![Entity::new("")]);
+    ///
+    /// assert!(cache.get(Path::new("src/src1.c"), &hash).is_some());
+    /// assert!(cache.get(Path::new("src/src1.c"), "0000000000000000").is_none());
+    /// assert!(cache.get(Path::new("src/src2.c"), &hash).is_none());
+    /// ```
+    pub fn get(&self, path: &Path, hash: &str) -> Option<&Vec<Entity>> {
+        self.entries
+            .get(path.to_string_lossy().as_ref())
+            .filter(|entry| entry.hash == hash)
+            .map(|entry| &entry.entities)
+    }
+
+    /// Inserts the entities extracted from the given project file.
+    pub fn insert(&mut self, path: &Path, hash: String, entities: Vec<Entity>) {
+        self.entries.insert(
+            path.to_string_lossy().into_owned(),
+            CacheEntry { hash, entities },
+        );
+    }
+}
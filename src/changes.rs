@@ -0,0 +1,328 @@
+use analysis::Function;
+use entity::{qualified_name, Entity, EntityType};
+use failure::Fallible;
+use regex::Regex;
+use std::{
+    collections::HashMap, fs::read_to_string, path::{Component, Path, PathBuf}, process::Command,
+};
+
+////////////////////////////////////////////////////////////////////////////////
+
+lazy_static! {
+    static ref INCLUDE: Regex =
+        Regex::new(r#"(?m)^\s*#\s*include\s*[<"](?P<header>[^>"]+)[>"]"#).unwrap();
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Runs git with the given arguments within the given directory and returns
+/// its output, or `None` when git failed.
+fn git(dir: &Path, arguments: &[&str]) -> Fallible<Option<String>> {
+    let output = Command::new("git")
+        .current_dir(dir)
+        .args(arguments)
+        .output()
+        .map_err(|err| format_err!("Unable to execute git: {}", err))?;
+
+    if !output.status.success() {
+        return Ok(None);
+    }
+
+    Ok(Some(String::from_utf8_lossy(&output.stdout).into_owned()))
+}
+
+/// Returns the canonical form of the given path, or the path itself when it
+/// does not exist.
+fn canonical(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Returns whether the signature and the description of both functions are equal.
+fn is_unchanged(function: &Function, previous: &Function) -> bool {
+    function.name == previous.name
+        && function.return_type == previous.return_type
+        && function.arguments == previous.arguments
+        && function.description == previous.description
+}
+
+/// Removes the functions from the `Entity` and all of its children which
+/// are found unchanged within the previous functions.
+fn remove_unchanged(
+    entity: &mut Entity,
+    parents: &mut Vec<String>,
+    previous: &HashMap<String, Vec<&Function>>,
+) {
+    entity.entities.retain(|child| match child {
+        EntityType::Function(function) => {
+            let parents: Vec<&str> = parents.iter().map(String::as_str).collect();
+            !previous
+                .get(&qualified_name(&parents, &function.name))
+                .map_or(false, |functions| {
+                    functions
+                        .iter()
+                        .any(|previous| is_unchanged(function, previous))
+                })
+        }
+        _ => true,
+    });
+
+    for child in &mut entity.entities {
+        if let EntityType::Entity(child) = child {
+            parents.push(child.name.clone());
+            remove_unchanged(child, parents, previous);
+            parents.pop();
+        }
+    }
+}
+
+/// Returns the files (transitively) included by the given file, which are
+/// found relative to the file or within one of the include directories.
+/// Includes which can not be resolved, e.g. system headers, are skipped.
+///
+/// # Example
+///
+/// ```
+/// use std::path::Path;
+/// use thinlinelib::changes;
+///
+/// let c_project_dir = Path::new("examples").join("c_project");
+/// let includes = changes::included_files(
+///     &c_project_dir.join("src").join("src1.c"),
+///     &[c_project_dir.join("include")],
+/// );
+///
+/// assert!(includes.iter().any(|include| include.ends_with("header1.h")));
+/// ```
+pub fn included_files(file: &Path, include_dirs: &[PathBuf]) -> Vec<PathBuf> {
+    let mut included: Vec<PathBuf> = Vec::new();
+    let mut pending = vec![canonical(file)];
+
+    while let Some(file) = pending.pop() {
+        let content = match read_to_string(&file) {
+            Ok(content) => content,
+            Err(_) => continue,
+        };
+
+        for captures in INCLUDE.captures_iter(&content) {
+            let header = Path::new(&captures["header"]);
+
+            // Includes are resolved from the including file first, then
+            // from the include directories.
+            let resolved = file
+                .parent()
+                .into_iter()
+                .chain(include_dirs.iter().map(PathBuf::as_path))
+                .map(|dir| dir.join(header))
+                .find(|path| path.is_file())
+                .map(|path| canonical(&path));
+
+            if let Some(resolved) = resolved {
+                if !included.contains(&resolved) {
+                    included.push(resolved.clone());
+                    pending.push(resolved);
+                }
+            }
+        }
+    }
+
+    included
+}
+
+/// Removes the functions from the current entities whose signature and
+/// description did not change compared to the previous entities.
+///
+/// # Example
+///
+/// ```
+/// use thinlinelib::analysis::Function;
+/// use thinlinelib::changes;
+/// use thinlinelib::entity::{Entity, EntityType};
+///
+/// let mut previous = Entity::new("");
+/// previous.add_entity::<Function>(EntityType::Function(Function::new("add")));
+/// previous.add_entity::<Function>(EntityType::Function(Function::new("sub")));
+///
+/// let mut current = previous.clone();
+/// if let Some(EntityType::Function(sub)) = current.entities.get_mut(1) {
+///     sub.set_description("# TESTCASE(sub)");
+/// }
+///
+/// let mut current = vec![current];
+/// changes::retain_changed(&mut current, &[previous]);
+///
+/// assert_eq!(current[0].functions().len(), 1);
+/// assert_eq!(current[0].functions()[0].name, "sub");
+/// ```
+pub fn retain_changed(current: &mut [Entity], previous: &[Entity]) {
+    let mut previous_functions = HashMap::new();
+    for entity in previous {
+        for (qualified_name, function) in entity.qualified_functions() {
+            previous_functions
+                .entry(qualified_name)
+                .or_insert_with(Vec::new)
+                .push(function);
+        }
+    }
+
+    for entity in current {
+        let mut parents = vec![entity.name.clone()];
+        remove_unchanged(entity, &mut parents, &previous_functions);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// The files of a git repository which changed since a given revision.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Changes {
+    /// The revision the changes are related to.
+    pub revision: String,
+
+    /// The top level directory of the git repository.
+    pub repository_dir: PathBuf,
+
+    /// The canonical paths of the changed files.
+    pub files: Vec<PathBuf>,
+}
+
+impl Changes {
+    /// Reads the files of the local git repository containing the given
+    /// directory which changed since the given revision. Besides committed
+    /// changes, this includes changes of the working tree and untracked files.
+    pub fn since(dir: &Path, revision: &str) -> Fallible<Self> {
+        let repository_dir = git(dir, &["rev-parse", "--show-toplevel"])?
+            .map(|toplevel| PathBuf::from(toplevel.trim()))
+            .ok_or_else(|| format_err!("'{}' is not within a git repository.", dir.display()))?;
+
+        if git(dir, &["rev-parse", "--verify", &format!("{}^{{commit}}", revision)])?.is_none() {
+            return Err(format_err!("Unknown git revision '{}'.", revision));
+        }
+
+        let mut files = Vec::new();
+        for arguments in &[
+            vec!["diff", "--name-only", revision, "--"],
+            vec!["ls-files", "--others", "--exclude-standard", "--full-name"],
+        ] {
+            // The listed paths are relative to the top level directory.
+            let listed_files = git(&repository_dir, arguments)?.ok_or_else(|| {
+                format_err!("Unable to list the files changed since '{}'.", revision)
+            })?;
+            files.extend(
+                listed_files
+                    .lines()
+                    .filter(|file| !file.is_empty())
+                    .map(|file| canonical(&repository_dir.join(file))),
+            );
+        }
+        debug!("Files changed since '{}': {:#?}", revision, files);
+
+        Ok(Self {
+            revision: String::from(revision),
+            repository_dir: canonical(&repository_dir),
+            files,
+        })
+    }
+
+    /// Creates the changes of the given files, which are not related to a
+    /// git revision.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::path::Path;
+    /// use thinlinelib::changes::Changes;
+    ///
+    /// let changes = Changes::from_files(&[Path::new("src").join("lib.rs")]);
+    ///
+    /// assert!(changes.is_changed(&Path::new("src").join("lib.rs")));
+    /// assert!(!changes.is_changed(&Path::new("src").join("main.rs")));
+    /// ```
+    pub fn from_files(files: &[PathBuf]) -> Self {
+        Self {
+            files: files.iter().map(|file| canonical(file)).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Returns whether the given file changed.
+    pub fn is_changed(&self, path: &Path) -> bool {
+        self.files.contains(&canonical(path))
+    }
+
+    /// Returns whether the given file includes one of the given canonical files.
+    fn includes_any(file: &Path, included_files: &[PathBuf]) -> bool {
+        let content = match read_to_string(file) {
+            Ok(content) => content,
+            Err(_) => return false,
+        };
+
+        INCLUDE.captures_iter(&content).any(|captures| {
+            let header = Path::new(&captures["header"]);
+
+            // Relative includes are resolved from the including file, any
+            // other include is matched by the trailing path components.
+            let relative_header = file.parent().map(|parent| canonical(&parent.join(header)));
+            let header_components: PathBuf = header
+                .components()
+                .filter(|component| match component {
+                    Component::Normal(_) => true,
+                    _ => false,
+                })
+                .collect();
+
+            included_files.iter().any(|included_file| {
+                relative_header.as_ref() == Some(included_file)
+                    || (header_components.components().next().is_some()
+                        && included_file.ends_with(&header_components))
+            })
+        })
+    }
+
+    /// Returns the given files which are affected by the changes. These are
+    /// the changed files and the files (transitively) including them.
+    pub fn affected_files(&self, files: &[PathBuf]) -> Vec<PathBuf> {
+        let mut affected = self.files.clone();
+
+        // The includers are searched until no further file is affected.
+        loop {
+            let includers: Vec<PathBuf> = files
+                .iter()
+                .map(|file| canonical(file))
+                .filter(|file| !affected.contains(file) && Self::includes_any(file, &affected))
+                .collect();
+
+            if includers.is_empty() {
+                break;
+            }
+            affected.extend(includers);
+        }
+
+        files
+            .iter()
+            .filter(|file| affected.contains(&canonical(file)))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the content the given file had at the revision, or `None`
+    /// when it did not exist.
+    pub fn previous_content(&self, path: &Path) -> Fallible<Option<String>> {
+        let canonical_path = canonical(path);
+        let relative_path = canonical_path
+            .strip_prefix(&self.repository_dir)
+            .map_err(|_| format_err!("'{}' is not within the git repository.", path.display()))?;
+
+        // Git expects the path separated by slashes on every platform.
+        let object = format!(
+            "{}:{}",
+            self.revision,
+            relative_path
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/")
+        );
+
+        git(&self.repository_dir, &["show", &object])
+    }
+}
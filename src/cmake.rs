@@ -0,0 +1,207 @@
+use failure::Fallible;
+use std::{
+    env, fs::{create_dir_all, File}, io::Write, path::{Path, PathBuf}, process::Command,
+};
+
+////////////////////////////////////////////////////////////////////////////////
+
+static CMAKE_FILE_NAME: &str = "CMakeLists.txt";
+static CMAKE_MINIMUM_VERSION: &str = "3.5";
+static CMAKE_BUILD_DIR: &str = "build";
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Converts a path to a string CMake is able to interpret on every OS.
+fn cmake_path(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+/// Returns the name of the test target built from the given test source.
+fn test_name(test_source: &Path) -> Option<String> {
+    test_source
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Default, Debug)]
+/// A CMake project which builds the synthesized test files and
+/// registers them with CTest.
+pub struct CMakeProject {
+    /// The name of the CMake project.
+    pub name: String,
+
+    /// The directory of the target project, paths are resolved relative to it.
+    pub project_dir: PathBuf,
+
+    /// The CMake package of the test framework (e.g. `GTest`).
+    pub test_package: Option<String>,
+
+    /// The libraries of the test framework each test is linked against.
+    pub test_libraries: Vec<String>,
+
+    /// The include directories of the target project.
+    pub include_dirs: Vec<String>,
+
+    /// The libraries of the target project each test is linked against.
+    pub lib_paths: Vec<String>,
+
+    /// The synthesized test sources, relative to the output directory.
+    pub test_sources: Vec<PathBuf>,
+}
+
+impl CMakeProject {
+    /// Creates a new `CMakeProject` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use thinlinelib::cmake::CMakeProject;
+    ///
+    /// let cmake_project = CMakeProject::new("thinline_tests", "examples/c_project");
+    ///
+    /// assert_eq!(cmake_project.name, "thinline_tests");
+    /// assert!(cmake_project.test_sources.is_empty());
+    /// ```
+    pub fn new<S: Into<String>, P: Into<PathBuf>>(name: S, project_dir: P) -> Self {
+        Self {
+            name: name.into(),
+            project_dir: project_dir.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Returns the CMake representation of an include directory or library.
+    /// Relative paths existing within the project directory are resolved
+    /// against it, absolute paths and plain library names (e.g. `pthread`)
+    /// are passed through unchanged.
+    fn project_path(&self, path: &str) -> String {
+        if Path::new(path).is_relative() && self.project_dir.join(path).exists() {
+            return format!("${{THINLINE_PROJECT_DIR}}/{}", cmake_path(Path::new(path)));
+        }
+
+        cmake_path(Path::new(path))
+    }
+
+    /// Renders the content of the `CMakeLists.txt`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use thinlinelib::cmake::CMakeProject;
+    ///
+    /// let mut cmake_project = CMakeProject::new("thinline_tests", "/project");
+    /// cmake_project.test_sources.push("test_src1_c.cpp".into());
+    ///
+    /// let cmake_lists = cmake_project.render();
+    ///
+    /// assert!(cmake_lists.contains("add_executable(test_src1_c test_src1_c.cpp)"));
+    /// assert!(cmake_lists.contains("add_test(NAME test_src1_c COMMAND test_src1_c)"));
+    /// ```
+    pub fn render(&self) -> String {
+        let project_dir = self
+            .project_dir
+            .canonicalize()
+            .unwrap_or_else(|_| self.project_dir.clone());
+
+        let mut lines = vec![
+            format!("cmake_minimum_required (VERSION {})", CMAKE_MINIMUM_VERSION),
+            format!("project({} C CXX)", self.name),
+            String::new(),
+            format!("set(THINLINE_PROJECT_DIR \"{}\")", cmake_path(&project_dir)),
+            String::new(),
+        ];
+
+        if let Some(test_package) = &self.test_package {
+            lines.push(format!("find_package({} REQUIRED)", test_package));
+        }
+        lines.push(String::from("enable_testing()"));
+        lines.push(String::new());
+
+        lines.push(String::from("include_directories(${CMAKE_CURRENT_SOURCE_DIR})"));
+        for include_dir in &self.include_dirs {
+            lines.push(format!(
+                "include_directories({})",
+                self.project_path(include_dir)
+            ));
+        }
+
+        let link_libraries = self
+            .test_libraries
+            .iter()
+            .cloned()
+            .chain(self.lib_paths.iter().map(|lib| self.project_path(lib)))
+            .collect::<Vec<String>>()
+            .join(" ");
+
+        for test_source in &self.test_sources {
+            let test_name = match test_name(test_source) {
+                Some(test_name) => test_name,
+                None => continue,
+            };
+
+            lines.push(String::new());
+            lines.push(format!(
+                "add_executable({} {})",
+                test_name,
+                cmake_path(test_source)
+            ));
+            if !link_libraries.is_empty() {
+                lines.push(format!(
+                    "target_link_libraries({} {})",
+                    test_name, link_libraries
+                ));
+            }
+            lines.push(format!(
+                "add_test(NAME {} COMMAND {})",
+                test_name, test_name
+            ));
+        }
+
+        lines.join("\n") + "\n"
+    }
+
+    /// Writes the `CMakeLists.txt` to the given output directory.
+    pub fn write(&self, output_dir: &Path) -> Fallible<()> {
+        create_dir_all(output_dir)?;
+
+        let cmake_file = output_dir.join(CMAKE_FILE_NAME);
+        info!("Writing CMake project '{}'", cmake_file.display());
+        File::create(cmake_file)?.write_all(self.render().as_bytes())?;
+
+        Ok(())
+    }
+
+    /// Configures and builds the CMake project written to the given output
+    /// directory. Returns the paths of the built test executables.
+    pub fn build(&self, output_dir: &Path) -> Fallible<Vec<PathBuf>> {
+        let build_dir = output_dir.join(CMAKE_BUILD_DIR);
+        create_dir_all(&build_dir)?;
+
+        info!("Building CMake project at '{}'", output_dir.display());
+        for arguments in &[vec![".."], vec!["--build", "."]] {
+            let status = Command::new("cmake")
+                .args(arguments)
+                .current_dir(&build_dir)
+                .status()?;
+            if !status.success() {
+                return Err(format_err!(
+                    "Executing 'cmake {}' failed.",
+                    arguments.join(" ")
+                ));
+            }
+        }
+
+        Ok(self
+            .test_sources
+            .iter()
+            .filter_map(|test_source| test_name(test_source))
+            .map(|test_name| {
+                build_dir
+                    .join(test_name)
+                    .with_extension(env::consts::EXE_EXTENSION)
+            })
+            .collect())
+    }
+}
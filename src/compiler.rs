@@ -0,0 +1,322 @@
+use cache::Fnv1a;
+use changes;
+use failure::{err_msg, Fallible};
+use project_parameters::CompilerParameters;
+use regex::Regex;
+use std::{
+    env, ffi::OsStr, fs::{create_dir_all, read, read_to_string, File}, io::Write,
+    path::{Path, PathBuf}, process::Command,
+};
+use synthesis::TestFile;
+
+////////////////////////////////////////////////////////////////////////////////
+
+static DEFAULT_CC: &str = "cc";
+static DEFAULT_CXX: &str = "c++";
+static OBJECT_DIR: &str = "obj";
+static OBJECT_EXTENSION: &str = "o";
+static OBJECT_HASH_EXTENSION: &str = "hash";
+
+lazy_static! {
+    /// Matches the diagnostics printed by gcc and clang, e.g.
+    /// `test_src1_c.cpp:12:5: error: 'x' was not declared in this scope`.
+    static ref DIAGNOSTIC: Regex = Regex::new(
+        r"^(?P<file>.+?):(?P<line>\d+):(?:\d+:)?\s*(?P<kind>(?:fatal )?error|warning):\s*(?P<message>.*)$"
+    ).unwrap();
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Returns the configured compiler, the one given by the environment variable
+/// or the default one, in this order.
+fn resolve_compiler(configured: &Option<String>, env_var: &str, default: &str) -> String {
+    configured
+        .clone()
+        .or_else(|| env::var(env_var).ok())
+        .unwrap_or_else(|| String::from(default))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Default, Debug)]
+/// A driver compiling the synthesized tests directly with the C/C++ compiler.
+pub struct CompilerDriver {
+    /// The compiler used for C test sources.
+    pub cc: String,
+
+    /// The compiler used for C++ test sources.
+    pub cxx: String,
+
+    /// The include directories passed to the compiler.
+    pub include_dirs: Vec<PathBuf>,
+
+    /// The libraries of the target project each test is linked against.
+    pub lib_paths: Vec<PathBuf>,
+
+    /// The libraries of the test framework each test is linked against.
+    pub libraries: Vec<String>,
+
+    /// Additional flags passed to the compiler.
+    pub flags: Vec<String>,
+}
+
+impl CompilerDriver {
+    /// Creates a new `CompilerDriver` instance from the given parameters.
+    /// Compilers which are not configured are taken from `CC` and `CXX`
+    /// and fall back to `cc` and `c++`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use thinlinelib::compiler::CompilerDriver;
+    /// use thinlinelib::project_parameters::CompilerParameters;
+    ///
+    /// let mut parameters = CompilerParameters::default();
+    /// parameters.cxx = Some(String::from("clang++"));
+    ///
+    /// let driver = CompilerDriver::new(&parameters);
+    ///
+    /// assert_eq!(driver.cxx, "clang++");
+    /// ```
+    pub fn new(parameters: &CompilerParameters) -> Self {
+        Self {
+            cc: resolve_compiler(&parameters.cc, "CC", DEFAULT_CC),
+            cxx: resolve_compiler(&parameters.cxx, "CXX", DEFAULT_CXX),
+            flags: parameters.flags.clone(),
+            ..Self::default()
+        }
+    }
+
+    /// Returns the compiler which is responsible for the given source.
+    fn compiler(&self, source: &Path) -> &str {
+        if source.extension() == Some(OsStr::new("c")) {
+            self.cc.as_str()
+        } else {
+            self.cxx.as_str()
+        }
+    }
+
+    fn compile_arguments(&self, source: &Path, object: &Path) -> Vec<String> {
+        let mut arguments = vec![
+            String::from("-c"),
+            source.to_string_lossy().into_owned(),
+            String::from("-o"),
+            object.to_string_lossy().into_owned(),
+        ];
+
+        for include_dir in &self.include_dirs {
+            arguments.push(format!("-I{}", include_dir.display()));
+        }
+        arguments.extend(self.flags.iter().cloned());
+
+        arguments
+    }
+
+    fn link_arguments(&self, object: &Path, executable: &Path) -> Vec<String> {
+        let mut arguments = vec![
+            object.to_string_lossy().into_owned(),
+            String::from("-o"),
+            executable.to_string_lossy().into_owned(),
+        ];
+        arguments.extend(self.flags.iter().cloned());
+
+        for lib_path in &self.lib_paths {
+            arguments.push(lib_path.to_string_lossy().into_owned());
+
+            // Shared libraries of the target project have to be found at runtime.
+            if !cfg!(target_os = "windows") {
+                if let Some(lib_dir) = lib_path.parent() {
+                    arguments.push(format!("-Wl,-rpath,{}", lib_dir.display()));
+                }
+            }
+        }
+
+        for library in &self.libraries {
+            arguments.push(format!("-l{}", library));
+        }
+
+        arguments
+    }
+
+    /// Returns the hash identifying an object compiled from the given source
+    /// content with the given compiler and arguments. The files included by
+    /// the source, e.g. the project headers, are part of the hash as well.
+    fn object_hash(
+        &self,
+        source: &Path,
+        content: &str,
+        compiler: &str,
+        arguments: &[String],
+    ) -> Fallible<String> {
+        let mut hasher = Fnv1a::new();
+        hasher.write(content.as_bytes());
+        for included_file in changes::included_files(source, &self.include_dirs) {
+            hasher.write(included_file.to_string_lossy().as_bytes());
+            hasher.write(&read(&included_file)?);
+        }
+        hasher.write(compiler.as_bytes());
+        for argument in arguments {
+            hasher.write(argument.as_bytes());
+        }
+
+        Ok(hasher.finish())
+    }
+
+    /// Maps the diagnostics within the compiler output to the test cases of
+    /// the test file and thus to the comments they were synthesized from.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use thinlinelib::compiler::CompilerDriver;
+    /// use thinlinelib::synthesis::{TestCase, TestFile};
+    ///
+    /// let mut test_file = TestFile::new("test_src1_c.cpp");
+    /// test_file.source = "src/src1.c".into();
+    /// test_file.test_cases.push(TestCase::new("test_int_no1", "TEST(a, b) {\n    x;\n}"));
+    ///
+    /// let rendered = "#include <gtest/gtest.h>\nTEST(a, b) {\n    x;\n}\n";
+    /// let output = "test_src1_c.cpp:3:5: error: 'x' was not declared in this scope";
+    ///
+    /// let diagnostics = CompilerDriver::map_diagnostics(&test_file, rendered, output);
+    ///
+    /// assert_eq!(diagnostics.len(), 1);
+    /// assert!(diagnostics[0].contains("test_int_no1"));
+    /// assert!(diagnostics[0].contains("src/src1.c"));
+    /// ```
+    pub fn map_diagnostics(test_file: &TestFile, rendered: &str, output: &str) -> Vec<String> {
+        output
+            .lines()
+            .map(|line| {
+                if let Some(captures) = DIAGNOSTIC.captures(line) {
+                    let in_test_file = test_file
+                        .path
+                        .file_name()
+                        .and_then(OsStr::to_str)
+                        .map_or(false, |file_name| captures["file"].ends_with(file_name));
+
+                    if in_test_file {
+                        if let Ok(line_no) = captures["line"].parse::<usize>() {
+                            if let Some(test_case) = test_file.test_case_at_line(rendered, line_no)
+                            {
+                                return format!(
+                                    "{}: {} (in the test description of '{}' at '{}')",
+                                    &captures["kind"],
+                                    &captures["message"],
+                                    test_case.function,
+                                    test_file.source.display()
+                                );
+                            }
+                        }
+                    }
+                }
+
+                String::from(line)
+            })
+            .collect()
+    }
+
+    /// Compiles the given test source to an object file. The compilation is
+    /// skipped when the object was already built from the same source content,
+    /// included files and arguments.
+    fn compile(&self, test_file: &TestFile, source: &Path, object: &Path) -> Fallible<()> {
+        let content = read_to_string(source)?;
+        let compiler = self.compiler(source);
+        let arguments = self.compile_arguments(source, object);
+        let hash = self.object_hash(source, &content, compiler, &arguments)?;
+        let hash_file = object.with_extension(OBJECT_HASH_EXTENSION);
+
+        let is_cached = read_to_string(&hash_file)
+            .ok()
+            .map_or(false, |cached_hash| cached_hash == hash);
+        if object.is_file() && is_cached {
+            debug!("Using cached object '{}'", object.display());
+            return Ok(());
+        }
+
+        info!("Compiling '{}'", source.display());
+        let output = Command::new(compiler).args(&arguments).output()?;
+        if !output.status.success() {
+            let diagnostics = Self::map_diagnostics(
+                test_file,
+                &content,
+                &String::from_utf8_lossy(&output.stderr),
+            );
+            return Err(format_err!(
+                "Compiling '{}' failed:\n{}",
+                source.display(),
+                diagnostics.join("\n")
+            ));
+        }
+
+        File::create(hash_file)?.write_all(hash.as_bytes())?;
+
+        Ok(())
+    }
+
+    /// Compiles and links the given test files located at the output directory.
+    /// Returns the paths of the built test executables.
+    pub fn build(&self, output_dir: &Path, test_files: &[TestFile]) -> Fallible<Vec<PathBuf>> {
+        let object_dir = output_dir.join(OBJECT_DIR);
+        create_dir_all(&object_dir)?;
+
+        let mut executables = Vec::new();
+        for test_file in test_files {
+            let source = output_dir.join(&test_file.path);
+            let test_name = test_file
+                .path
+                .file_stem()
+                .ok_or_else(|| err_msg("Unable to get the name of the test file."))?;
+            let object = object_dir.join(test_name).with_extension(OBJECT_EXTENSION);
+            let executable = output_dir
+                .join(test_name)
+                .with_extension(env::consts::EXE_EXTENSION);
+
+            self.compile(test_file, &source, &object)?;
+
+            info!("Linking '{}'", executable.display());
+            let output = Command::new(self.compiler(&source))
+                .args(&self.link_arguments(&object, &executable))
+                .output()?;
+            if !output.status.success() {
+                return Err(format_err!(
+                    "Linking '{}' failed:\n{}",
+                    executable.display(),
+                    String::from_utf8_lossy(&output.stderr)
+                ));
+            }
+
+            executables.push(executable);
+        }
+
+        Ok(executables)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod compiler {
+    use super::CompilerDriver;
+    use std::{env, fs::{create_dir_all, write}};
+
+    #[test]
+    fn object_hash_of_changed_header() {
+        let test_dir = env::temp_dir().join("thinline_object_hash");
+        create_dir_all(&test_dir).unwrap();
+        let source = test_dir.join("test_src1_c.cpp");
+        let header = test_dir.join("__tlg__.h");
+        write(&source, "#include \"__tlg__.h\"\n").unwrap();
+
+        let driver = CompilerDriver::default();
+        let hash = |header_content: &str| {
+            write(&header, header_content).unwrap();
+            driver
+                .object_hash(&source, "#include \"__tlg__.h\"\n", "c++", &[])
+                .unwrap()
+        };
+
+        assert_eq!(hash("struct a { int x; };"), hash("struct a { int x; };"));
+        assert_ne!(hash("struct a { int x; };"), hash("struct a { long x; };"));
+    }
+}
@@ -0,0 +1,769 @@
+use analysis::{normalize_type, Argument, ArgumentKind, Description, Function};
+use entity::{Entity, EntityType};
+use failure::Fallible;
+use regex::Regex;
+use std::collections::HashMap;
+
+////////////////////////////////////////////////////////////////////////////////
+
+lazy_static! {
+    static ref TEST_CASE: Regex = Regex::new(r"^#TEST_?CASE\((?P<name>.+)\)$").unwrap();
+    static ref TEST_CLASS: Regex = Regex::new(r"^#TEST_?CLASS\((?P<name>.+)\)$").unwrap();
+    static ref TYPES: Regex = Regex::new(r"^#TYPES\((?P<types>.+)\)$").unwrap();
+    static ref CONTEXT: Regex = Regex::new(
+        r"^#(?P<context>SET_UP|TEAR_DOWN|CONSTRUCTOR|DESTRUCTOR|CLASS)(?:_CONTEXT)?:?$"
+    ).unwrap();
+    static ref ASSERTION: Regex = Regex::new(
+        r"^#(?P<comparison>EQ|NE|LT|LE|GT|GE)\[(?P<call>.*?)=>(?P<expected>.*)\]$"
+    ).unwrap();
+    static ref FUNCTION_CALL: Regex =
+        Regex::new(r"^(?P<receiver>.*?)TL_FCT\((?P<arguments>.*)\)$").unwrap();
+    static ref CALL_ARGUMENT: Regex =
+        Regex::new(r"^(?P<name>[A-Za-z_][A-Za-z0-9_]*):(?P<value>.+)$").unwrap();
+    static ref INTEGER_LITERAL: Regex =
+        Regex::new(r"^[-+]?(?:0[xX][0-9a-fA-F]+|[0-9]+)(?P<suffix>[uUlL]*)$").unwrap();
+    static ref FLOAT_LITERAL: Regex = Regex::new(concat!(
+        r"^[-+]?(?:[0-9]+\.[0-9]*|\.[0-9]+|[0-9]+(?:\.[0-9]*)?[eE][-+]?[0-9]+)",
+        r"(?P<suffix>[fFlL]?)$"
+    )).unwrap();
+    static ref CAST: Regex = Regex::new(concat!(
+        r"^(?:\((?P<type>[A-Za-z_][A-Za-z0-9_:<>,*& ]*)\)[^-+*/%&|^<>=?]",
+        r"|static_cast<(?P<static_type>.+?)>\()"
+    )).unwrap();
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Splits a comma separated argument list while respecting brackets and quotes.
+fn split_arguments(arguments: &str) -> Vec<&str> {
+    let mut split = Vec::new();
+    let mut depth = 0;
+    let mut quote: Option<char> = None;
+    let mut start = 0;
+
+    for (i, c) in arguments.char_indices() {
+        match (quote, c) {
+            (Some(q), _) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"') | (None, '\'') => quote = Some(c),
+            (None, '(') | (None, '[') | (None, '{') => depth += 1,
+            (None, ')') | (None, ']') | (None, '}') => depth -= 1,
+            (None, ',') if depth == 0 => {
+                split.push(arguments[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+
+    let last = arguments[start..].trim();
+    if !last.is_empty() || !split.is_empty() {
+        split.push(last);
+    }
+
+    split
+}
+
+/// Returns the type of a `TL_FCT` argument value, when it can be told from the
+/// value itself. This is the case for literals and casts.
+fn value_type(value: &str) -> Option<String> {
+    let value = value.trim();
+
+    if let Some(captures) = CAST.captures(value) {
+        return captures
+            .name("type")
+            .or_else(|| captures.name("static_type"))
+            .map(|atype| String::from(atype.as_str()));
+    }
+
+    if let Some(captures) = INTEGER_LITERAL.captures(value) {
+        let atype = match captures["suffix"].to_lowercase().as_str() {
+            "" => "int",
+            "u" => "unsigned int",
+            "l" => "long",
+            "ul" | "lu" => "unsigned long",
+            "ll" => "long long",
+            _ => "unsigned long long",
+        };
+        return Some(String::from(atype));
+    }
+
+    if let Some(captures) = FLOAT_LITERAL.captures(value) {
+        let atype = match captures["suffix"].to_lowercase().as_str() {
+            "f" => "float",
+            "l" => "long double",
+            _ => "double",
+        };
+        return Some(String::from(atype));
+    }
+
+    let atype = match value {
+        "true" | "false" | "True" | "False" => "bool",
+        "nullptr" => "std::nullptr_t",
+        _ if value.starts_with('"') => "const char*",
+        _ if value.starts_with('\'') => "char",
+        _ => return None,
+    };
+    Some(String::from(atype))
+}
+
+/// Returns whether a value of the given type is passed to an argument of the
+/// given type without any conversion.
+fn is_exact_type(argument_type: &str, value_type: &str) -> bool {
+    let argument_type = normalize_type(argument_type);
+    let argument_type = argument_type
+        .trim_start_matches("const ")
+        .trim_end_matches('&')
+        .trim_end_matches(" const");
+
+    // Descriptions are stored without spaces, so types are compared without them.
+    let without_spaces =
+        |atype: &str| -> String { atype.chars().filter(|c| !c.is_whitespace()).collect() };
+    let (argument_type, value_type) = (without_spaces(argument_type), without_spaces(value_type));
+
+    match value_type.as_str() {
+        "constchar*" => argument_type.ends_with("char*") || argument_type.contains("string"),
+        "std::nullptr_t" => argument_type.ends_with('*'),
+        _ => argument_type == value_type,
+    }
+}
+
+/// Returns whether a `Function` takes an argument of the given name. Any
+/// name is taken by functions with variadic keyword arguments.
+fn takes_argument(function: &Function, name: &str) -> bool {
+    function
+        .arguments
+        .iter()
+        .any(|argument| argument.name == name || argument.kind == ArgumentKind::Keywords)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// A call of the function under test (`TL_FCT`) within an assertion.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FunctionCall {
+    /// The expression the function is called on, e.g. `this->class_inst->`.
+    pub receiver: String,
+
+    /// The named arguments the function is called with.
+    pub arguments: Vec<Argument>,
+}
+
+impl FunctionCall {
+    /// Parses a `FunctionCall` like `TL_FCT(no1: 5, no2: 2)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use thinlinelib::dsl::FunctionCall;
+    ///
+    /// let call = FunctionCall::parse("this->inst->TL_FCT(no1:5,no2:foo(1,2))").unwrap();
+    ///
+    /// assert_eq!(call.receiver, "this->inst->");
+    /// assert_eq!(call.arguments.len(), 2);
+    /// assert_eq!(call.arguments[1].name, "no2");
+    /// assert_eq!(call.arguments[1].value, Some(String::from("foo(1,2)")));
+    /// ```
+    pub fn parse(call: &str) -> Fallible<Self> {
+        let captures = FUNCTION_CALL
+            .captures(call.trim())
+            .ok_or_else(|| format_err!("'{}' is no call of TL_FCT.", call))?;
+
+        let mut arguments = Vec::new();
+        for argument in split_arguments(&captures["arguments"]) {
+            let argument_captures = CALL_ARGUMENT.captures(argument).ok_or_else(|| {
+                format_err!("Argument '{}' is not of the form 'name: value'.", argument)
+            })?;
+
+            let mut arg = Argument::new(&argument_captures["name"], None);
+            arg.set_value(argument_captures["value"].trim());
+            arguments.push(arg);
+        }
+
+        Ok(Self {
+            receiver: String::from(&captures["receiver"]),
+            arguments,
+        })
+    }
+
+    /// Returns whether the call lacks the given argument of the called
+    /// function, which may only be omitted when it is optional.
+    fn lacks_argument(&self, argument: &Argument) -> bool {
+        !argument.is_optional() && !self.arguments.iter().any(|arg| arg.name == argument.name)
+    }
+
+    /// Resolves the overload of a function the `FunctionCall` refers to.
+    ///
+    /// The overloads taking the named arguments are narrowed down to those
+    /// whose argument types match the types of the literal and casted values.
+    /// An error is returned when no overload or more than one remains.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use thinlinelib::analysis::{Argument, Function};
+    /// use thinlinelib::dsl::FunctionCall;
+    ///
+    /// let mut add_int = Function::new("add");
+    /// add_int.set_arguments(&vec![
+    ///     Argument::new("no1", Some("int")),
+    ///     Argument::new("no2", Some("int")),
+    /// ]);
+    /// let mut add_double = Function::new("add");
+    /// add_double.set_arguments(&vec![
+    ///     Argument::new("no1", Some("double")),
+    ///     Argument::new("no2", Some("double")),
+    /// ]);
+    /// let overloads = vec![&add_int, &add_double];
+    ///
+    /// let call = FunctionCall::parse("TL_FCT(no1: 1.5, no2: 2.0)").unwrap();
+    /// assert_eq!(call.resolve(&overloads).unwrap().signature(), "add(double, double)");
+    ///
+    /// let call = FunctionCall::parse("TL_FCT(no1: x, no2: y)").unwrap();
+    /// assert!(call.resolve(&overloads).is_err());
+    /// ```
+    pub fn resolve<'a>(&self, overloads: &[&'a Function]) -> Fallible<&'a Function> {
+        let name = overloads
+            .first()
+            .map_or("TL_FCT", |function| function.qualified_name.as_str());
+        let argument_names = self
+            .arguments
+            .iter()
+            .map(|argument| argument.name.as_str())
+            .collect::<Vec<&str>>()
+            .join(", ");
+
+        let named: Vec<&Function> = overloads
+            .iter()
+            .filter(|function| {
+                self.arguments
+                    .iter()
+                    .all(|argument| takes_argument(function, &argument.name))
+                    && function
+                        .arguments
+                        .iter()
+                        .all(|argument| !self.lacks_argument(argument))
+            })
+            .cloned()
+            .collect();
+
+        let typed: Vec<&Function> = named
+            .iter()
+            .filter(|function| {
+                self.arguments.iter().all(|argument| {
+                    let value_type = argument.value.as_ref().and_then(|value| value_type(value));
+                    let argument_type = function
+                        .arguments
+                        .iter()
+                        .find(|arg| arg.name == argument.name)
+                        .and_then(|arg| arg.atype.as_ref());
+
+                    match (argument_type, value_type) {
+                        (Some(argument_type), Some(value_type)) => {
+                            is_exact_type(argument_type, &value_type)
+                        }
+                        _ => true,
+                    }
+                })
+            })
+            .cloned()
+            .collect();
+
+        // Without an exact match, the values may still be converted implicitly.
+        let candidates = if typed.is_empty() { named } else { typed };
+
+        match candidates.len() {
+            0 => Err(format_err!(
+                "No overload of '{}' takes the arguments ({}).",
+                name,
+                argument_names
+            )),
+            1 => Ok(candidates[0]),
+            _ => Err(format_err!(
+                "The call of '{}' with ({}) is ambiguous between '{}'.",
+                name,
+                argument_names,
+                candidates
+                    .iter()
+                    .map(|function| function.signature())
+                    .collect::<Vec<String>>()
+                    .join("', '")
+            )),
+        }
+    }
+
+    /// Renders the `FunctionCall` as code calling the given function.
+    ///
+    /// Python functions are called by their name within the module and get
+    /// the values by keyword, while async ones are awaited. Objective-C
+    /// methods are sent the message of their selector to the receiver. Any
+    /// other function gets the values in the order of its arguments.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use thinlinelib::analysis::{Argument, Function};
+    /// use thinlinelib::dsl::FunctionCall;
+    ///
+    /// let mut add = Function::new("add");
+    /// add.set_arguments(&vec![
+    ///     Argument::new("no1", Some("int")),
+    ///     Argument::new("no2", Some("int")),
+    /// ]);
+    ///
+    /// let call = FunctionCall::parse("this->calc->TL_FCT(no2: 2, no1: 1)").unwrap();
+    /// assert_eq!(call.render(&add).unwrap(), "this->calc->add(1, 2)");
+    ///
+    /// let call = FunctionCall::parse("TL_FCT(no2: 2)").unwrap();
+    /// assert!(call.render(&add).is_err());
+    /// ```
+    pub fn render(&self, function: &Function) -> Fallible<String> {
+        for argument in &self.arguments {
+            if !takes_argument(function, &argument.name) {
+                return Err(format_err!(
+                    "'{}' has no argument '{}'.",
+                    function.name,
+                    argument.name
+                ));
+            }
+        }
+        if let Some(argument) = function
+            .arguments
+            .iter()
+            .find(|argument| self.lacks_argument(argument))
+        {
+            return Err(format_err!(
+                "Argument '{}' of '{}' is missing.",
+                argument.name,
+                function.name
+            ));
+        }
+
+        if function.language.as_ref().map(String::as_str) == Some("python") {
+            return Ok(self.render_python(function));
+        }
+
+        let value = |name: &str| {
+            self.arguments
+                .iter()
+                .find(|argument| argument.name == name)
+                .and_then(|argument| argument.value.as_ref())
+                .map(String::as_str)
+        };
+
+        // Values are passed by position, so an omitted argument has to be
+        // followed by omitted ones only.
+        let mut values = Vec::new();
+        let mut omitted: Option<&str> = None;
+        for argument in &function.arguments {
+            match (value(&argument.name), omitted) {
+                (Some(_), Some(omitted)) => {
+                    return Err(format_err!(
+                        "Argument '{}' of '{}' is omitted before '{}'.",
+                        omitted,
+                        function.name,
+                        argument.name
+                    ));
+                }
+                (Some(value), None) => values.push(value),
+                (None, _) => omitted = omitted.or(Some(argument.name.as_str())),
+            }
+        }
+
+        // Selectors like `add:to:` contain a part for every argument.
+        if function.name.contains(':') {
+            if self.receiver.trim().is_empty() {
+                return Err(format_err!(
+                    "The call of '{}' needs a receiver.",
+                    function.name
+                ));
+            }
+            let parts: Vec<&str> = function
+                .name
+                .split(':')
+                .filter(|part| !part.is_empty())
+                .collect();
+            let message = if values.is_empty() {
+                parts.join(" ")
+            } else {
+                parts
+                    .iter()
+                    .zip(values)
+                    .map(|(part, value)| format!("{}:{}", part, value))
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            };
+
+            return Ok(format!("[{} {}]", self.receiver.trim(), message));
+        }
+
+        let name = if self.receiver.is_empty() {
+            function.qualified_name.clone()
+        } else {
+            format!("{}{}", self.receiver, function.name)
+        };
+
+        Ok(format!("{}({})", name, values.join(", ")))
+    }
+
+    /// Renders the call of a Python function. The module itself is left out
+    /// of the name, since its functions and classes are imported by the tests.
+    fn render_python(&self, function: &Function) -> String {
+        let name = if self.receiver.is_empty() {
+            match function.qualified_name.find('.') {
+                Some(index) => String::from(&function.qualified_name[index + 1..]),
+                None => function.name.clone(),
+            }
+        } else {
+            format!("{}{}", self.receiver, function.name)
+        };
+        let values = self
+            .arguments
+            .iter()
+            .map(|argument| {
+                let value = argument.value.as_ref().map_or("", String::as_str);
+                let is_variadic = function.arguments.iter().any(|arg| {
+                    arg.name == argument.name && arg.kind == ArgumentKind::Variadic
+                });
+                if is_variadic {
+                    format!("*{}", value)
+                } else {
+                    format!("{}={}", argument.name, value)
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+        let call = format!("{}({})", name, values);
+
+        if function.is_async {
+            format!("await {}", call)
+        } else {
+            call
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// An assertion like `# EQ[TL_FCT(no1: 5, no2: 2) => 7]`.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Assertion {
+    /// The comparison of the assertion (e.g. `EQ` or `NE`).
+    pub comparison: String,
+
+    /// The call of the function under test.
+    pub call: FunctionCall,
+
+    /// The expected value.
+    pub expected: String,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// A parsed line of a description.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Statement {
+    /// Starts a test class, e.g. `# TESTCLASS(c1)` or `# TEST_CLASS(c1)`.
+    TestClass(String),
+
+    /// Starts a test case, e.g. `# TESTCASE(c1::Add)` or `# TEST_CASE(c1::Add)`.
+    TestCase(String),
+
+    /// Lists the types a function template is tested with, e.g. `# TYPES(int, double)`.
+    Types(Vec<String>),
+
+    /// Starts a context of a test class, e.g. `# SET_UP_CONTEXT:` or `# SET_UP`.
+    Context(String),
+
+    /// An assertion on the function under test.
+    Assertion(Assertion),
+
+    /// Plain code which is taken over into the test.
+    Code(String),
+}
+
+impl Statement {
+    /// Parses a single description line.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use thinlinelib::dsl::Statement;
+    ///
+    /// assert_eq!(
+    ///     Statement::parse("#TESTCASE(Source1::TestPtr)").unwrap(),
+    ///     Statement::TestCase(String::from("Source1::TestPtr"))
+    /// );
+    /// assert_eq!(
+    ///     Statement::parse("int test_no = 2;").unwrap(),
+    ///     Statement::Code(String::from("int test_no = 2;"))
+    /// );
+    /// assert!(Statement::parse("#UNKNOWN[TL_FCT() => 7]").is_err());
+    /// ```
+    pub fn parse(line: &str) -> Fallible<Self> {
+        if !line.starts_with('#') {
+            return Ok(Statement::Code(String::from(line)));
+        }
+
+        if let Some(captures) = TEST_CASE.captures(line) {
+            return Ok(Statement::TestCase(String::from(&captures["name"])));
+        }
+
+        if let Some(captures) = TEST_CLASS.captures(line) {
+            return Ok(Statement::TestClass(String::from(&captures["name"])));
+        }
+
+        if let Some(captures) = TYPES.captures(line) {
+            return Ok(Statement::Types(
+                split_arguments(&captures["types"])
+                    .into_iter()
+                    .map(String::from)
+                    .collect(),
+            ));
+        }
+
+        if let Some(captures) = CONTEXT.captures(line) {
+            return Ok(Statement::Context(String::from(&captures["context"])));
+        }
+
+        if let Some(captures) = ASSERTION.captures(line) {
+            return Ok(Statement::Assertion(Assertion {
+                comparison: String::from(&captures["comparison"]),
+                call: FunctionCall::parse(&captures["call"])?,
+                expected: String::from(captures["expected"].trim()),
+            }));
+        }
+
+        Err(format_err!("Unknown directive '{}'.", line))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Parses all lines of a `Description`.
+pub fn parse(description: &Description) -> Fallible<Vec<Statement>> {
+    description
+        .lines
+        .iter()
+        .map(|line| Statement::parse(line))
+        .collect()
+}
+
+/// Returns the names of the test cases within a `Description`.
+///
+/// # Example
+///
+/// ```
+/// use thinlinelib::analysis::Description;
+/// use thinlinelib::dsl;
+///
+/// let mut description = Description::new();
+/// description.set("# TESTCASE(first)\n# EQ[TL_FCT() => 7]\n# TESTCASE(second)");
+///
+/// assert_eq!(dsl::test_case_names(&description), vec!["first", "second"]);
+/// ```
+pub fn test_case_names(description: &Description) -> Vec<String> {
+    description
+        .lines
+        .iter()
+        .filter_map(|line| match Statement::parse(line) {
+            Ok(Statement::TestCase(name)) => Some(name),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the types listed by the `TYPES` statements of a `Description`.
+///
+/// # Example
+///
+/// ```
+/// use thinlinelib::analysis::Description;
+/// use thinlinelib::dsl;
+///
+/// let mut description = Description::new();
+/// description.set("# TYPES(int, double)\n# TESTCASE(add)");
+///
+/// assert_eq!(dsl::test_types(&description), vec!["int", "double"]);
+/// ```
+pub fn test_types(description: &Description) -> Vec<String> {
+    description
+        .lines
+        .iter()
+        .filter_map(|line| match Statement::parse(line) {
+            Ok(Statement::Types(types)) => Some(types),
+            _ => None,
+        })
+        .flat_map(|types| types)
+        .collect()
+}
+
+/// Returns the code lines of the contexts within a `Description` by the name
+/// of the context, e.g. the lines following `# SET_UP_CONTEXT:` by `SET_UP`.
+///
+/// # Example
+///
+/// ```
+/// use thinlinelib::analysis::Description;
+/// use thinlinelib::dsl;
+///
+/// let mut description = Description::new();
+/// description.set("# TESTCLASS(c1)\n# SET_UP_CONTEXT:\nint a = 1;\n# TEAR_DOWN\na = 0;");
+///
+/// let contexts = dsl::test_contexts(&description);
+/// assert_eq!(contexts["SET_UP"], vec!["int a = 1;"]);
+/// assert_eq!(contexts["TEAR_DOWN"], vec!["a = 0;"]);
+/// ```
+pub fn test_contexts(description: &Description) -> HashMap<String, Vec<String>> {
+    let mut contexts = HashMap::new();
+    let mut current_context: Option<String> = None;
+
+    for line in &description.lines {
+        match Statement::parse(line) {
+            Ok(Statement::Context(context)) => current_context = Some(context),
+            Ok(Statement::Code(code)) => {
+                if let Some(context) = &current_context {
+                    if !code.is_empty() {
+                        contexts
+                            .entry(context.clone())
+                            .or_insert_with(Vec::new)
+                            .push(code);
+                    }
+                }
+            }
+            _ => current_context = None,
+        }
+    }
+
+    contexts
+}
+
+/// Validates the description of a `Function` and returns the found problems.
+///
+/// Beside the syntax of the statements it is checked that assertions are
+/// placed within test cases and that the arguments of `TL_FCT` match the
+/// arguments of the function. Defaulted and variadic arguments may be omitted.
+///
+/// # Example
+///
+/// ```
+/// use thinlinelib::analysis::{Argument, Function};
+/// use thinlinelib::dsl;
+///
+/// let mut function = Function::new("add");
+/// function.set_arguments(&vec![
+///     Argument::new("no1", Some("int")),
+///     Argument::new("no2", Some("int")),
+/// ]);
+/// function.set_description("# TESTCASE(add)\n# EQ[TL_FCT(no1: 1, no3: 2) => 3]");
+///
+/// let problems = dsl::validate_function(&function);
+///
+/// assert_eq!(problems.len(), 2);
+/// ```
+pub fn validate_function(function: &Function) -> Vec<String> {
+    validate_overload(function, &[function])
+}
+
+/// Validates the description of a `Function` which is one of the given
+/// overloads. Beside the checks of `validate_function`, every `TL_FCT` has
+/// to resolve unambiguously to the described overload.
+pub fn validate_overload(function: &Function, overloads: &[&Function]) -> Vec<String> {
+    let mut problems = Vec::new();
+
+    if let Some(description) = &function.description {
+        let mut in_test_case = false;
+
+        for line in &description.lines {
+            match Statement::parse(line) {
+                Ok(Statement::TestCase(_)) => in_test_case = true,
+                Ok(Statement::Types(_)) => {
+                    if function.template_parameters.is_empty() {
+                        problems.push(format!(
+                            "Types are given for '{}', which is no template.",
+                            function.name
+                        ));
+                    }
+                }
+                Ok(Statement::Assertion(assertion)) => {
+                    if !in_test_case {
+                        problems.push(format!(
+                            "Assertion '{}' of '{}' is not part of a test case.",
+                            line, function.name
+                        ));
+                    }
+
+                    let argument_problems = problems.len();
+                    for argument in &assertion.call.arguments {
+                        if !takes_argument(function, &argument.name) {
+                            problems.push(format!(
+                                "'{}' has no argument '{}'.",
+                                function.name, argument.name
+                            ));
+                        }
+                    }
+
+                    for argument in &function.arguments {
+                        if assertion.call.lacks_argument(argument) {
+                            problems.push(format!(
+                                "Argument '{}' of '{}' is missing in '{}'.",
+                                argument.name, function.name, line
+                            ));
+                        }
+                    }
+
+                    // Overloads are only resolved for calls matching the described one.
+                    if overloads.len() > 1 && problems.len() == argument_problems {
+                        match assertion.call.resolve(overloads) {
+                            Ok(resolved) if resolved.signature() != function.signature() => {
+                                problems.push(format!(
+                                    "'{}' of '{}' calls '{}'.",
+                                    line,
+                                    function.signature(),
+                                    resolved.signature()
+                                ));
+                            }
+                            Ok(_) => {}
+                            Err(err) => problems.push(format!("{} ({})", err, function.name)),
+                        }
+                    }
+                }
+                Ok(_) => {}
+                Err(err) => problems.push(format!("{} ({})", err, function.name)),
+            }
+        }
+    }
+
+    problems
+}
+
+/// Validates the descriptions of an `Entity` and all of its children.
+pub fn validate_entity(entity: &Entity) -> Vec<String> {
+    let mut problems = Vec::new();
+
+    if let Some(description) = &entity.description {
+        for line in &description.lines {
+            if let Err(err) = Statement::parse(line) {
+                problems.push(format!("{} ({})", err, entity.name));
+            }
+        }
+    }
+
+    let functions = entity.functions();
+    for child in &entity.entities {
+        match child {
+            EntityType::Function(function) => {
+                let overloads: Vec<&Function> = functions
+                    .iter()
+                    .filter(|overload| overload.name == function.name)
+                    .cloned()
+                    .collect();
+                problems.extend(validate_overload(function, &overloads));
+            }
+            EntityType::Entity(entity) => problems.extend(validate_entity(entity)),
+            _ => {}
+        }
+    }
+
+    problems
+}
@@ -0,0 +1,151 @@
+use analysis::{Description, ProjectFile};
+use dsl::Statement;
+use entity::{Entity, EntityType};
+use failure::Fallible;
+use serde_json;
+use serde_yaml;
+use std::path::PathBuf;
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, PartialEq)]
+/// The machine-readable formats the analysis can be dumped in.
+pub enum DumpFormat {
+    Json,
+    Yaml,
+}
+
+impl DumpFormat {
+    /// Returns the `DumpFormat` connected to the given name.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use thinlinelib::dump::DumpFormat;
+    ///
+    /// assert_eq!(DumpFormat::from_name("json").unwrap(), DumpFormat::Json);
+    /// assert_eq!(DumpFormat::from_name("yaml").unwrap(), DumpFormat::Yaml);
+    /// assert!(DumpFormat::from_name("xml").is_err());
+    /// ```
+    pub fn from_name(name: &str) -> Fallible<Self> {
+        match name {
+            "json" => Ok(DumpFormat::Json),
+            "yaml" => Ok(DumpFormat::Yaml),
+            _ => Err(format_err!("Unknown dump format '{}'.", name)),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// The parsed test DSL of an entity description.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParsedDescription {
+    /// The name of the described entity.
+    pub entity: String,
+
+    /// The statements of the description which could be parsed.
+    pub statements: Vec<Statement>,
+
+    /// The problems of the lines which could not be parsed.
+    pub problems: Vec<String>,
+}
+
+impl ParsedDescription {
+    /// Parses the given `Description` of the named entity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use thinlinelib::analysis::Description;
+    /// use thinlinelib::dump::ParsedDescription;
+    ///
+    /// let mut description = Description::new();
+    /// description.set("# TESTCASE(case)\n# XY[TL_FCT() => 7]");
+    ///
+    /// let parsed = ParsedDescription::parse("fct", &description);
+    ///
+    /// assert_eq!(parsed.statements.len(), 1);
+    /// assert_eq!(parsed.problems.len(), 1);
+    /// ```
+    pub fn parse<S: Into<String>>(entity: S, description: &Description) -> Self {
+        let mut parsed = Self {
+            entity: entity.into(),
+            statements: Vec::new(),
+            problems: Vec::new(),
+        };
+
+        for line in &description.lines {
+            match Statement::parse(line) {
+                Ok(statement) => parsed.statements.push(statement),
+                Err(err) => parsed.problems.push(err.to_string()),
+            }
+        }
+
+        parsed
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// The analysis result of a single project file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FileDump {
+    /// The path of the project file.
+    pub path: PathBuf,
+
+    /// The entity tree extracted from the project file.
+    pub entities: Vec<Entity>,
+
+    /// The parsed test DSL of every described entity within the file.
+    pub descriptions: Vec<ParsedDescription>,
+}
+
+impl FileDump {
+    /// Creates the `FileDump` of the given `ProjectFile`.
+    pub fn new(project_file: &ProjectFile) -> Self {
+        let entities = project_file.entities().to_vec();
+        let mut descriptions = Vec::new();
+        for entity in &entities {
+            Self::collect_descriptions(entity, &mut descriptions);
+        }
+
+        Self {
+            path: project_file.path.clone(),
+            entities,
+            descriptions,
+        }
+    }
+
+    /// Collects the parsed descriptions of an `Entity` and all of its children.
+    fn collect_descriptions(entity: &Entity, descriptions: &mut Vec<ParsedDescription>) {
+        if let Some(description) = &entity.description {
+            descriptions.push(ParsedDescription::parse(entity.name.as_str(), description));
+        }
+
+        for child in &entity.entities {
+            match child {
+                EntityType::Entity(entity) => Self::collect_descriptions(entity, descriptions),
+                EntityType::Function(function) => {
+                    if let Some(description) = &function.description {
+                        descriptions
+                            .push(ParsedDescription::parse(function.name.as_str(), description));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Serializes the analysis results of the given project files in the given format.
+pub fn dump(project_files: &[ProjectFile], format: &DumpFormat) -> Fallible<String> {
+    let file_dumps: Vec<FileDump> = project_files.iter().map(FileDump::new).collect();
+
+    Ok(match format {
+        DumpFormat::Json => serde_json::to_string_pretty(&file_dumps)?,
+        DumpFormat::Yaml => serde_yaml::to_string(&file_dumps)?,
+    })
+}
@@ -42,6 +42,20 @@ pub enum EntityType {
     TestFunction(TestFunction),
 }
 
+impl EntityType {
+    /// Returns the name of the encapsulated entity, which is empty for
+    /// test classes.
+    pub fn name(&self) -> &str {
+        match self {
+            EntityType::Entity(entity) => &entity.name,
+            EntityType::Enum(enumeration) => &enumeration.name,
+            EntityType::Function(function) => &function.name,
+            EntityType::TestClass(_) => "",
+            EntityType::TestFunction(test_function) => &test_function.name,
+        }
+    }
+}
+
 pub trait EntityConversion {
     fn convert(entity_type: &EntityType) -> Option<&Self>;
     fn convert_mut(entity_type: &mut EntityType) -> Option<&mut Self>;
@@ -55,11 +69,175 @@ implement_conversion!(TestFunction);
 
 ////////////////////////////////////////////////////////////////////////////////
 
+/// The kinds of scopes an `Entity` can represent.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum EntityKind {
+    /// The unnamed root of the entities of a project file.
+    Index,
+
+    /// A C++ namespace.
+    Namespace,
+
+    /// A class.
+    Class,
+
+    /// A C++ struct.
+    Struct,
+
+    /// A C++ union.
+    Union,
+
+    /// A Python module.
+    Module,
+}
+
+impl Default for EntityKind {
+    fn default() -> Self {
+        EntityKind::Index
+    }
+}
+
+impl EntityKind {
+    /// Returns the keyword introducing the kind in the entity tree, which
+    /// is empty for an index.
+    pub fn keyword(&self) -> &str {
+        match self {
+            EntityKind::Index => "",
+            EntityKind::Namespace => "namespace",
+            EntityKind::Class => "class",
+            EntityKind::Struct => "struct",
+            EntityKind::Union => "union",
+            EntityKind::Module => "module",
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// The access specifiers of class members.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Access {
+    Public,
+    Protected,
+    Private,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// A visitor of an entity tree, which is passed to `Entity::walk`.
+///
+/// # Example
+///
+/// ```
+/// use thinlinelib::analysis::Function;
+/// use thinlinelib::entity::{Entity, EntityType, EntityVisitor};
+///
+/// struct Depth {
+///     current: usize,
+///     max: usize,
+/// }
+///
+/// impl<'a> EntityVisitor<'a> for Depth {
+///     fn enter(&mut self, _entity: &'a EntityType, parents: &[&'a str]) {
+///         self.current += 1;
+///         self.max = self.max.max(parents.len() + 1);
+///     }
+///
+///     fn leave(&mut self, _entity: &'a EntityType, _parents: &[&'a str]) {
+///         self.current -= 1;
+///     }
+/// }
+///
+/// let mut class = Entity::new("class");
+/// class.add_entity::<Function>(EntityType::Function(Function::new("fct")));
+///
+/// let mut index = Entity::new("");
+/// index.add_entity::<Entity>(EntityType::Entity(class));
+///
+/// let mut depth = Depth { current: 0, max: 0 };
+/// index.walk(&mut depth);
+///
+/// assert_eq!(depth.current, 0);
+/// assert_eq!(depth.max, 2);
+/// ```
+pub trait EntityVisitor<'a> {
+    /// Called before the children of the given entity are visited. The
+    /// parents are the names of the enclosing entities, starting at the root.
+    fn enter(&mut self, _entity: &'a EntityType, _parents: &[&'a str]) {}
+
+    /// Called after the children of the given entity were visited.
+    fn leave(&mut self, _entity: &'a EntityType, _parents: &[&'a str]) {}
+}
+
+/// Collects the functions of an entity tree together with their qualified names.
+#[derive(Default)]
+struct FunctionCollector<'a> {
+    functions: Vec<(String, &'a Function)>,
+}
+
+impl<'a> EntityVisitor<'a> for FunctionCollector<'a> {
+    fn enter(&mut self, entity: &'a EntityType, parents: &[&'a str]) {
+        if let EntityType::Function(function) = entity {
+            self.functions
+                .push((qualified_name(parents, &function.name), function));
+        }
+    }
+}
+
+/// Finds the first entity of an entity tree with the given qualified name.
+struct EntityFinder<'a, 'b> {
+    qualified_name: &'b str,
+    found: Option<&'a EntityType>,
+}
+
+impl<'a, 'b> EntityVisitor<'a> for EntityFinder<'a, 'b> {
+    fn enter(&mut self, entity: &'a EntityType, parents: &[&'a str]) {
+        if self.found.is_none() && qualified_name(parents, entity.name()) == self.qualified_name {
+            self.found = Some(entity);
+        }
+    }
+}
+
+/// Returns the name qualified by the given parent names, which are joined
+/// by `::`. Empty names of anonymous parents are skipped.
+///
+/// # Example
+///
+/// ```
+/// use thinlinelib::entity::qualified_name;
+///
+/// assert_eq!(qualified_name(&["ns1", "", "c1"], "add"), "ns1::c1::add");
+/// assert_eq!(qualified_name(&[], "add"), "add");
+/// ```
+pub fn qualified_name(parents: &[&str], name: &str) -> String {
+    qualified_name_with(parents, name, "::")
+}
+
+/// Returns the name qualified by the given parent names, which are joined
+/// by the given separator.
+fn qualified_name_with(parents: &[&str], name: &str, separator: &str) -> String {
+    parents
+        .iter()
+        .chain(Some(&name))
+        .filter(|name| !name.is_empty())
+        .cloned()
+        .collect::<Vec<&str>>()
+        .join(separator)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
 /// The representation of an `Entity` as a possbile generic node on the
 /// abstract syntax tree. An `Entity` has to be kind of an `EntityType`.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Entity {
     pub name: String,
+    pub qualified_name: String,
+    pub kind: EntityKind,
+    pub access: Option<Access>,
+    pub bases: Vec<String>,
+    pub is_abstract: bool,
+    pub template_parameters: Vec<String>,
     pub entities: Vec<EntityType>,
     pub description: Option<Description>,
 }
@@ -75,16 +253,33 @@ impl Entity {
     /// let class = Entity::new("testClass");
     ///
     /// assert_eq!(class.name, "testClass");
+    /// assert_eq!(class.qualified_name, "testClass");
     /// assert!(class.entities.is_empty());
     /// ```
     pub fn new<S: Into<String>>(name: S) -> Self {
+        let name = name.into();
+
         Self {
-            name: name.into(),
+            qualified_name: name.clone(),
+            name,
+            kind: EntityKind::Index,
+            access: None,
+            bases: Vec::new(),
+            is_abstract: false,
+            template_parameters: Vec::new(),
             entities: Vec::new(),
             description: None,
         }
     }
 
+    /// Creates a new `Entity` instance of the given kind.
+    pub fn with_kind<S: Into<String>>(name: S, kind: EntityKind) -> Self {
+        Self {
+            kind,
+            ..Self::new(name)
+        }
+    }
+
     /// Returns a reference to the Entity encapsulated within the EntityType.
     pub fn ref_from_entity_type<T>(entity_type: &EntityType) -> Option<&T>
     where
@@ -137,6 +332,271 @@ impl Entity {
         return entity_vec;
     }
 
+    /// Returns whether the `Entity` is a class, struct or union which can be
+    /// instantiated, i.e. is not abstract.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use thinlinelib::entity::{Entity, EntityKind};
+    ///
+    /// let mut class = Entity::with_kind("shape", EntityKind::Class);
+    /// assert!(class.is_instantiable());
+    ///
+    /// class.is_abstract = true;
+    /// assert!(!class.is_instantiable());
+    /// assert!(!Entity::with_kind("ns1", EntityKind::Namespace).is_instantiable());
+    /// ```
+    pub fn is_instantiable(&self) -> bool {
+        match self.kind {
+            EntityKind::Class | EntityKind::Struct | EntityKind::Union => !self.is_abstract,
+            _ => false,
+        }
+    }
+
+    /// Sets the qualified names of the children of the `Entity` and all of
+    /// their children, which are prefixed by the qualified name of the `Entity`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use thinlinelib::analysis::Function;
+    /// use thinlinelib::entity::{Entity, EntityKind, EntityType};
+    ///
+    /// let mut class = Entity::with_kind("c1", EntityKind::Class);
+    /// class.add_entity::<Function>(EntityType::Function(Function::new("add")));
+    ///
+    /// let mut namespace = Entity::with_kind("ns1", EntityKind::Namespace);
+    /// namespace.add_entity::<Entity>(EntityType::Entity(class));
+    ///
+    /// let mut index = Entity::new("");
+    /// index.add_entity::<Entity>(EntityType::Entity(namespace));
+    /// index.qualify();
+    ///
+    /// let function = index.find("ns1::c1::add").unwrap();
+    /// if let EntityType::Function(function) = function {
+    ///     assert_eq!(function.qualified_name, "ns1::c1::add");
+    /// }
+    /// ```
+    pub fn qualify(&mut self) {
+        self.qualify_with("::");
+    }
+
+    /// Sets the qualified names of the children of the `Entity` and all of
+    /// their children like `qualify`, joining the names with the given
+    /// separator, e.g. `.` for Python.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use thinlinelib::analysis::Function;
+    /// use thinlinelib::entity::{Entity, EntityKind, EntityType};
+    ///
+    /// let mut class = Entity::with_kind("Calculator", EntityKind::Class);
+    /// class.add_entity::<Function>(EntityType::Function(Function::new("add")));
+    ///
+    /// let mut module = Entity::with_kind("calc", EntityKind::Module);
+    /// module.add_entity::<Entity>(EntityType::Entity(class));
+    /// module.qualify_with(".");
+    ///
+    /// let function = module.find("calc::Calculator::add").unwrap();
+    /// if let EntityType::Function(function) = function {
+    ///     assert_eq!(function.qualified_name, "calc.Calculator.add");
+    /// }
+    /// ```
+    pub fn qualify_with(&mut self, separator: &str) {
+        let parents = [self.qualified_name.as_str()];
+
+        for entity in &mut self.entities {
+            match entity {
+                EntityType::Entity(entity) => {
+                    entity.qualified_name = qualified_name_with(&parents, &entity.name, separator);
+                    entity.qualify_with(separator);
+                }
+                EntityType::Enum(enumeration) => {
+                    enumeration.qualified_name =
+                        qualified_name_with(&parents, &enumeration.name, separator);
+                }
+                EntityType::Function(function) => {
+                    function.qualified_name =
+                        qualified_name_with(&parents, &function.name, separator);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Walks through the children of the `Entity` and all of their children
+    /// in depth-first order, calling the hooks of the given visitor.
+    pub fn walk<'a, V>(&'a self, visitor: &mut V)
+    where
+        V: EntityVisitor<'a>,
+    {
+        let mut parents = Vec::new();
+        if !self.name.is_empty() {
+            parents.push(self.name.as_str());
+        }
+
+        Self::walk_entities(&self.entities, &mut parents, visitor);
+    }
+
+    fn walk_entities<'a, V>(entities: &'a [EntityType], parents: &mut Vec<&'a str>, visitor: &mut V)
+    where
+        V: EntityVisitor<'a>,
+    {
+        for entity in entities {
+            visitor.enter(entity, parents);
+
+            if let EntityType::Entity(child) = entity {
+                // Anonymous entities do not qualify the names of their children.
+                let is_named = !child.name.is_empty();
+                if is_named {
+                    parents.push(child.name.as_str());
+                }
+                Self::walk_entities(&child.entities, parents, visitor);
+                if is_named {
+                    parents.pop();
+                }
+            }
+
+            visitor.leave(entity, parents);
+        }
+    }
+
+    /// Returns the functions of the `Entity` and all of its children
+    /// together with their qualified names.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use thinlinelib::analysis::Function;
+    /// use thinlinelib::entity::{Entity, EntityType};
+    ///
+    /// let mut class = Entity::new("c1");
+    /// class.add_entity::<Function>(EntityType::Function(Function::new("add_two_numbers")));
+    ///
+    /// let mut namespace = Entity::new("ns1");
+    /// namespace.add_entity::<Entity>(EntityType::Entity(class));
+    /// namespace.add_entity::<Function>(EntityType::Function(Function::new("sub")));
+    ///
+    /// let functions = namespace.qualified_functions();
+    ///
+    /// assert_eq!(functions[0].0, "ns1::c1::add_two_numbers");
+    /// assert_eq!(functions[1].0, "ns1::sub");
+    /// ```
+    pub fn qualified_functions(&self) -> Vec<(String, &Function)> {
+        let mut collector = FunctionCollector::default();
+        self.walk(&mut collector);
+
+        collector.functions
+    }
+
+    /// Returns the functions of the `Entity` and all of its children.
+    pub fn all_functions(&self) -> Vec<&Function> {
+        self.qualified_functions()
+            .into_iter()
+            .map(|(_, function)| function)
+            .collect()
+    }
+
+    /// Returns the functions of the `Entity` and all of its children which
+    /// have a description.
+    pub fn described_functions(&self) -> Vec<&Function> {
+        self.all_functions()
+            .into_iter()
+            .filter(|function| function.description.is_some())
+            .collect()
+    }
+
+    /// Returns the first child of the `Entity` or of one of its children
+    /// with the given qualified name.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use thinlinelib::analysis::Function;
+    /// use thinlinelib::entity::{Entity, EntityType};
+    ///
+    /// let mut class = Entity::new("c1");
+    /// class.add_entity::<Function>(EntityType::Function(Function::new("add_two_numbers")));
+    ///
+    /// let mut namespace = Entity::new("ns1");
+    /// namespace.add_entity::<Entity>(EntityType::Entity(class));
+    ///
+    /// let mut index = Entity::new("");
+    /// index.add_entity::<Entity>(EntityType::Entity(namespace));
+    ///
+    /// assert!(index.find("ns1::c1::add_two_numbers").is_some());
+    /// assert!(index.find("ns1::c1").is_some());
+    /// assert!(index.find("c1::add_two_numbers").is_none());
+    /// ```
+    pub fn find(&self, qualified_name: &str) -> Option<&EntityType> {
+        let mut finder = EntityFinder {
+            qualified_name,
+            found: None,
+        };
+        self.walk(&mut finder);
+
+        finder.found
+    }
+
+    /// Formats the children of the `Entity` as lines of an indented tree.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use thinlinelib::analysis::Function;
+    /// use thinlinelib::entity::{Entity, EntityKind, EntityType};
+    ///
+    /// let mut class = Entity::with_kind("circle", EntityKind::Class);
+    /// class.bases.push(String::from("shape"));
+    /// class.add_entity::<Function>(EntityType::Function(Function::new("fct")));
+    ///
+    /// let mut index = Entity::new("");
+    /// index.add_entity::<Entity>(EntityType::Entity(class));
+    ///
+    /// assert_eq!(index.tree_lines(0), vec!["class circle : shape", "  fct()"]);
+    /// ```
+    pub fn tree_lines(&self, depth: usize) -> Vec<String> {
+        let indent = "  ".repeat(depth);
+        let mut lines = Vec::new();
+
+        for entity in &self.entities {
+            match entity {
+                EntityType::Entity(entity) => {
+                    let mut line = indent.clone();
+                    if entity.is_abstract {
+                        line.push_str("abstract ");
+                    }
+                    if !entity.kind.keyword().is_empty() {
+                        line.push_str(entity.kind.keyword());
+                        line.push(' ');
+                    }
+                    line.push_str(&entity.name);
+                    if !entity.template_parameters.is_empty() {
+                        line.push_str(&format!("<{}>", entity.template_parameters.join(", ")));
+                    }
+                    if !entity.bases.is_empty() {
+                        line.push_str(" : ");
+                        line.push_str(&entity.bases.join(", "));
+                    }
+
+                    lines.push(line);
+                    lines.extend(entity.tree_lines(depth + 1));
+                }
+                EntityType::Enum(enumeration) => {
+                    lines.push(format!("{}enum {}", indent, enumeration.name));
+                }
+                EntityType::Function(function) => {
+                    lines.push(format!("{}{}", indent, function));
+                }
+                _ => {}
+            }
+        }
+
+        lines
+    }
+
     /// Sets the description for the `Entity`.
     pub fn set_description(&mut self, description: &str) {
         if self.description.is_none() {
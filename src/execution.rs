@@ -0,0 +1,59 @@
+use failure::Fallible;
+use std::{
+    fmt::{Display, Formatter, Result}, path::{Path, PathBuf}, process::Command,
+};
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// The result of executing a built test executable.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestResult {
+    /// The executed test executable.
+    pub executable: PathBuf,
+
+    /// Indicator whether all tests of the executable passed.
+    pub passed: bool,
+
+    /// The output the executable printed.
+    pub output: String,
+}
+
+impl TestResult {
+    /// Executes the given test executable and returns its `TestResult`.
+    pub fn execute(executable: &Path) -> Fallible<Self> {
+        info!("Executing '{}'", executable.display());
+        let output = Command::new(executable).output()?;
+
+        Ok(Self {
+            executable: executable.to_path_buf(),
+            passed: output.status.success(),
+            output: format!(
+                "{}{}",
+                String::from_utf8_lossy(&output.stdout),
+                String::from_utf8_lossy(&output.stderr)
+            ),
+        })
+    }
+}
+
+impl Display for TestResult {
+    /// Formats a TestResult to be displayed by std output.
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(
+            f,
+            "{} {}",
+            if self.passed { "PASS" } else { "FAIL" },
+            self.executable.display()
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Executes all given test executables.
+pub fn execute(executables: &[PathBuf]) -> Fallible<Vec<TestResult>> {
+    executables
+        .iter()
+        .map(|executable| TestResult::execute(executable))
+        .collect()
+}
@@ -1,11 +1,16 @@
-use analysis::{Analysis, Argument, Enum, Function};
+use analysis::{
+    Argument, ArgumentKind, Description, Enum, Function, Linkage, Location, ModulePath,
+    ProjectFile, Qualifier, StorageClass,
+};
 use clang;
-use entity::{Entity, EntityType};
-use failure::{err_msg, Fallible};
+use entity::{Access, Entity, EntityKind, EntityType};
+use failure::{err_msg, Error, Fallible};
+use nom::{Context, Err as ParseError};
 use python_parser::{
-    ast::{CompoundStatement, Expression, Statement}, file_input, make_strspan,
+    ast::{CompoundStatement, Expression, StarParams, Statement}, file_input, make_strspan,
+    visitors::printer::format_module,
 };
-use std::{fs::File, io::Read};
+use std::{collections::HashMap, ffi::OsStr, fmt::Debug, fs::File, io::Read, path::Path};
 
 ////////////////////////////////////////////////////////////////////////////////
 
@@ -20,9 +25,346 @@ lazy_static! {
 
 ////////////////////////////////////////////////////////////////////////////////
 
-pub trait LanguageType: Default {
-    fn file_types() -> &'static [&'static str];
-    fn extract_entities<T: LanguageType>(analysis: &Analysis<T>) -> Fallible<()>;
+/// A language backend extracting the entities of the project files
+/// written in its language.
+///
+/// Besides the built-in backends for C, C++, Objective-C and Python, further
+/// languages can be supported by implementing this trait and registering the
+/// backend at `Thinline::register_backend`. The project files whose extension
+/// is one of the `file_types` are then handed to `extract_entities`, which has to
+/// return the entity tree of the file. Every returned `Entity` is a root of
+/// the file, which is usually a single unnamed index entity containing the
+/// functions, classes and namespaces of the file. The test descriptions are
+/// taken from the `description` of the contained entities and functions,
+/// which should be created from the raw comments by `extract_description`.
+/// Backends set the `kind` of the entities they create, whereas the
+/// qualified names are set by the analysis afterwards.
+/// As the project files are analyzed in parallel, backends have to be
+/// `Send` and `Sync`.
+///
+/// # Example
+///
+/// ```
+/// extern crate failure;
+/// extern crate thinlinelib;
+///
+/// use failure::Fallible;
+/// use std::fs::read_to_string;
+/// use thinlinelib::analysis::{Function, ProjectFile};
+/// use thinlinelib::entity::{Entity, EntityType};
+/// use thinlinelib::language_type::{BackendRegistry, LanguageBackend};
+///
+/// /// Extracts every line `fn <name>` as function.
+/// #[derive(Debug)]
+/// struct Minimal;
+///
+/// impl LanguageBackend for Minimal {
+///     fn name(&self) -> &str {
+///         "minimal"
+///     }
+///
+///     fn file_types(&self) -> &[&str] {
+///         &["min"]
+///     }
+///
+///     fn extract_entities(
+///         &self,
+///         project_file: &ProjectFile,
+///         _flags: &[String],
+///     ) -> Fallible<Vec<Entity>> {
+///         let mut index = Entity::new("");
+///         for line in read_to_string(&project_file.path)?.lines() {
+///             if line.starts_with("fn ") {
+///                 let function = Function::new(line.trim_left_matches("fn "));
+///                 index.add_entity::<Function>(EntityType::Function(function));
+///             }
+///         }
+///
+///         Ok(vec![index])
+///     }
+/// }
+///
+/// fn main() {
+///     let mut registry = BackendRegistry::new();
+///     registry.register(Box::new(Minimal));
+///
+///     assert_eq!(registry.by_extension("min").unwrap().name(), "minimal");
+/// }
+/// ```
+pub trait LanguageBackend: Debug + Send + Sync {
+    /// Returns the name of the language, as used for the `language`
+    /// parameter of the project config.
+    fn name(&self) -> &str;
+
+    /// Returns the file extensions (without leading dot) of the files
+    /// written in the language. Projects can override them by the
+    /// `file_types` parameter of the project config.
+    fn file_types(&self) -> &[&str];
+
+    /// Extracts the entity trees of the given project file. The flags are
+    /// the include directories, defines and analysis flags of the project
+    /// config, which a backend may pass to its parser. Depending on the strictness
+    /// of the analysis, a failed file fails the analysis or is skipped.
+    fn extract_entities(
+        &self,
+        project_file: &ProjectFile,
+        flags: &[String],
+    ) -> Fallible<Vec<Entity>>;
+
+    /// Returns the `ModulePath` the given project file is imported with,
+    /// relative to the given root directory if the file is located within.
+    /// The default implementation returns `None` for languages without modules.
+    fn module_path(&self, _path: &Path, _root: Option<&Path>) -> Option<ModulePath> {
+        None
+    }
+
+    /// Returns the separator the names of nested entities are qualified
+    /// with. The default implementation returns `::`, as used by the C family.
+    fn scope_separator(&self) -> &str {
+        "::"
+    }
+
+    /// Creates the `Description` of an entity from its raw comment. The
+    /// default implementation strips leading whitespace, `*` and `/` from
+    /// every line and removes empty lines.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use thinlinelib::language_type::{LanguageBackend, C};
+    ///
+    /// let description = C.extract_description("/**\n * # TESTCASE(add)\n */");
+    ///
+    /// assert_eq!(description.lines, vec!["#TESTCASE(add)"]);
+    /// ```
+    fn extract_description(&self, comment: &str) -> Description {
+        let mut description = Description::new();
+        description.set(comment);
+
+        description
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// The registry of the available language backends, which can be looked up
+/// by their name or the file extensions they are responsible for.
+#[derive(Debug)]
+pub struct BackendRegistry {
+    backends: Vec<Box<dyn LanguageBackend>>,
+    file_types: HashMap<String, Vec<String>>,
+}
+
+impl Default for BackendRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BackendRegistry {
+    /// Creates a new `BackendRegistry` containing the built-in backends.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use thinlinelib::language_type::BackendRegistry;
+    ///
+    /// let registry = BackendRegistry::new();
+    ///
+    /// assert_eq!(registry.names(), vec!["objc", "cpp", "c", "python"]);
+    /// ```
+    pub fn new() -> Self {
+        let mut registry = Self {
+            backends: Vec::new(),
+            file_types: HashMap::new(),
+        };
+
+        // C, C++ and Objective-C claim `.h` headers. They are analyzed as
+        // Objective-C only within pure Objective-C projects and as C++ only
+        // within projects without C, as C is registered last.
+        registry.register(Box::new(ObjC));
+        registry.register(Box::new(Cpp));
+        registry.register(Box::new(C));
+        registry.register(Box::new(Python));
+
+        registry
+    }
+
+    /// Registers the given backend. A backend already registered with the
+    /// same name is replaced.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use thinlinelib::language_type::{BackendRegistry, C};
+    ///
+    /// let mut registry = BackendRegistry::new();
+    /// registry.register(Box::new(C));
+    ///
+    /// assert_eq!(registry.names(), vec!["objc", "cpp", "python", "c"]);
+    /// ```
+    pub fn register(&mut self, backend: Box<dyn LanguageBackend>) {
+        self.backends.retain(|registered| registered.name() != backend.name());
+        self.backends.push(backend);
+    }
+
+    /// Returns the names of the registered backends.
+    pub fn names(&self) -> Vec<&str> {
+        self.backends.iter().map(|backend| backend.name()).collect()
+    }
+
+    /// Overrides the file extensions the backend with the given name is
+    /// responsible for. Overridden extensions take precedence over the ones
+    /// other backends claim by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::path::Path;
+    /// use thinlinelib::language_type::BackendRegistry;
+    ///
+    /// let mut registry = BackendRegistry::new();
+    /// registry.set_file_types("cpp", &["cpp", "h"]);
+    ///
+    /// assert_eq!(registry.file_types("cpp"), vec!["cpp", "h"]);
+    /// assert_eq!(registry.for_path(Path::new("src1.h"), &["c", "cpp"]).unwrap().name(), "cpp");
+    /// assert!(registry.for_path(Path::new("src1.hpp"), &["c", "cpp"]).is_none());
+    /// ```
+    pub fn set_file_types<S: AsRef<str>>(&mut self, name: &str, file_types: &[S]) {
+        self.file_types.insert(
+            String::from(name),
+            file_types
+                .iter()
+                .map(|file_type| String::from(file_type.as_ref()))
+                .collect(),
+        );
+    }
+
+    /// Returns the file extensions the backend with the given name is
+    /// responsible for, which are either overridden or its default ones.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use thinlinelib::language_type::BackendRegistry;
+    ///
+    /// let registry = BackendRegistry::new();
+    ///
+    /// assert_eq!(registry.file_types("python"), vec!["py", "pyi"]);
+    /// assert!(registry.file_types("rust").is_empty());
+    /// ```
+    pub fn file_types(&self, name: &str) -> Vec<&str> {
+        if let Some(file_types) = self.file_types.get(name) {
+            return file_types.iter().map(String::as_str).collect();
+        }
+
+        self.by_name(name)
+            .map(|backend| backend.file_types().to_vec())
+            .unwrap_or_default()
+    }
+
+    /// Returns whether the file extensions of the backend with the given
+    /// name are overridden and contain the given extension.
+    fn is_overridden_for(&self, name: &str, extension: &str) -> bool {
+        self.file_types
+            .get(name)
+            .map_or(false, |file_types| file_types.iter().any(|file_type| file_type == extension))
+    }
+
+    /// Returns the backend registered with the given name.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use thinlinelib::language_type::BackendRegistry;
+    ///
+    /// let registry = BackendRegistry::new();
+    ///
+    /// assert_eq!(registry.by_name("cpp").unwrap().name(), "cpp");
+    /// assert!(registry.by_name("rust").is_none());
+    /// ```
+    pub fn by_name(&self, name: &str) -> Option<&dyn LanguageBackend> {
+        self.backends
+            .iter()
+            .find(|backend| backend.name() == name)
+            .map(|backend| backend.as_ref())
+    }
+
+    /// Returns the backend responsible for the given file extension. When
+    /// several backends claim the extension, an overridden claim wins over
+    /// the default ones and otherwise the last registered backend wins.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use thinlinelib::language_type::BackendRegistry;
+    ///
+    /// let registry = BackendRegistry::new();
+    ///
+    /// assert_eq!(registry.by_extension("h").unwrap().name(), "c");
+    /// assert_eq!(registry.by_extension("cc").unwrap().name(), "cpp");
+    /// assert!(registry.by_extension("rs").is_none());
+    /// ```
+    pub fn by_extension(&self, extension: &str) -> Option<&dyn LanguageBackend> {
+        let names = self.names();
+        self.for_extension(extension, &names)
+    }
+
+    /// Returns the backend responsible for the given path, considering only
+    /// the backends of the given languages.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::path::Path;
+    /// use thinlinelib::language_type::BackendRegistry;
+    ///
+    /// let registry = BackendRegistry::new();
+    ///
+    /// assert_eq!(registry.for_path(Path::new("src1.h"), &["cpp"]).unwrap().name(), "cpp");
+    /// assert_eq!(registry.for_path(Path::new("src1.h"), &["c", "cpp"]).unwrap().name(), "c");
+    /// assert_eq!(registry.for_path(Path::new("src1.h"), &["objc"]).unwrap().name(), "objc");
+    /// assert_eq!(registry.for_path(Path::new("src1.h"), &["cpp", "objc"]).unwrap().name(), "cpp");
+    /// ```
+    pub fn for_path<S: AsRef<str>>(
+        &self,
+        path: &Path,
+        languages: &[S],
+    ) -> Option<&dyn LanguageBackend> {
+        let extension = path.extension().and_then(OsStr::to_str)?;
+
+        self.for_extension(extension, languages)
+    }
+
+    /// Returns the backend of the given languages responsible for the
+    /// given file extension.
+    fn for_extension<S: AsRef<str>>(
+        &self,
+        extension: &str,
+        languages: &[S],
+    ) -> Option<&dyn LanguageBackend> {
+        let backends: Vec<&dyn LanguageBackend> = self
+            .backends
+            .iter()
+            .rev()
+            .map(|backend| backend.as_ref())
+            .filter(|backend| {
+                languages
+                    .iter()
+                    .any(|language| language.as_ref() == backend.name())
+            })
+            .collect();
+
+        backends
+            .iter()
+            .find(|backend| self.is_overridden_for(backend.name(), extension))
+            .or_else(|| {
+                backends
+                    .iter()
+                    .find(|backend| self.file_types(backend.name()).contains(&extension))
+            })
+            .cloned()
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -31,11 +373,20 @@ pub trait LanguageType: Default {
 struct CFamily;
 
 impl CFamily {
+    /// Returns the arguments clang parses a project file with, which set
+    /// the language explicitly, as the file extensions may be configured.
+    fn arguments(language: &str, flags: &[String]) -> Vec<String> {
+        let mut arguments = vec![String::from("-x"), String::from(language)];
+        arguments.extend(flags.iter().cloned());
+
+        arguments
+    }
+
     fn format_arguments(arguments: &[clang::Entity]) -> Fallible<Vec<Argument>> {
         let mut args = Vec::new();
 
         for argument in arguments {
-            args.push(Argument::new(
+            let mut arg = Argument::new(
                 argument.get_display_name().unwrap_or(String::new()),
                 Some(
                     argument
@@ -43,30 +394,211 @@ impl CFamily {
                         .ok_or_else(|| err_msg("Argument type can not be parsed from signature."))?
                         .get_display_name(),
                 ),
-            ));
+            );
+            if let Some(value) = Self::default_value(argument) {
+                arg.set_value(&value);
+            }
+            args.push(arg);
         }
 
         Ok(args)
     }
 
+    /// Joins the spellings of tokens, separating only adjacent words.
+    fn join_tokens(tokens: &[String]) -> String {
+        let is_word = |c: Option<char>| c.map_or(false, |c| c.is_alphanumeric() || c == '_');
+
+        let mut joined = String::new();
+        for token in tokens {
+            if is_word(joined.chars().last()) && is_word(token.chars().next()) {
+                joined.push(' ');
+            }
+            joined.push_str(token);
+        }
+
+        joined
+    }
+
+    /// Returns the default value of a clang parameter entity, which is read
+    /// from the tokens following its `=`.
+    fn default_value(argument: &clang::Entity) -> Option<String> {
+        let mut tokens: Vec<String> = argument
+            .get_range()?
+            .tokenize()
+            .iter()
+            .map(|token| token.get_spelling())
+            .collect();
+
+        // Some libclang versions also return the token following the range.
+        let count = |spelling: &str| tokens.iter().filter(|token| *token == spelling).count();
+        let is_trailing = match tokens.last().map(String::as_str) {
+            Some(",") => true,
+            Some(")") => count(")") > count("("),
+            _ => false,
+        };
+        if is_trailing {
+            tokens.pop();
+        }
+
+        let assignment = tokens.iter().position(|token| token == "=")?;
+        Some(Self::join_tokens(&tokens[assignment + 1..]))
+    }
+
+    /// Returns the names of the template parameters of a clang template entity.
+    fn template_parameters(entity: &clang::Entity) -> Vec<String> {
+        entity
+            .get_children()
+            .iter()
+            .filter(|child| match child.get_kind() {
+                clang::EntityKind::TemplateTypeParameter
+                | clang::EntityKind::NonTypeTemplateParameter
+                | clang::EntityKind::TemplateTemplateParameter => true,
+                _ => false,
+            })
+            .map(|child| child.get_display_name().unwrap_or_default())
+            .collect()
+    }
+
+    /// Returns the access specifier of a clang entity, which is only given
+    /// for class members.
+    fn access(entity: &clang::Entity) -> Option<Access> {
+        match entity.get_accessibility() {
+            Some(clang::Accessibility::Public) => Some(Access::Public),
+            Some(clang::Accessibility::Protected) => Some(Access::Protected),
+            Some(clang::Accessibility::Private) => Some(Access::Private),
+            None => None,
+        }
+    }
+
+    /// Returns the spellings of the tokens declaring a clang entity, which
+    /// excludes the body of a function definition.
+    fn declaration_tokens(entity: &clang::Entity) -> Vec<String> {
+        entity
+            .get_range()
+            .map(|range| {
+                range
+                    .tokenize()
+                    .iter()
+                    .map(|token| token.get_spelling())
+                    .take_while(|spelling| spelling != "{")
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the qualifiers of a clang function entity. Those which are
+    /// not exposed by libclang are read from the declaring tokens.
+    fn qualifiers(entity: &clang::Entity) -> Vec<Qualifier> {
+        let tokens = Self::declaration_tokens(entity);
+        let has_token = |keyword: &str| tokens.iter().any(|token| token == keyword);
+        let is_assigned = |keyword: &str| {
+            tokens
+                .windows(2)
+                .any(|pair| pair[0] == "=" && pair[1] == keyword)
+        };
+
+        let mut qualifiers = Vec::new();
+        if has_token("inline") {
+            qualifiers.push(Qualifier::Inline);
+        }
+        if entity.is_virtual_method() {
+            qualifiers.push(Qualifier::Virtual);
+        }
+        if entity.is_pure_virtual_method() {
+            qualifiers.push(Qualifier::PureVirtual);
+        }
+        if entity.is_const_method() {
+            qualifiers.push(Qualifier::Const);
+        }
+        if has_token("noexcept") {
+            qualifiers.push(Qualifier::Noexcept);
+        }
+        if has_token("constexpr") {
+            qualifiers.push(Qualifier::Constexpr);
+        }
+        if is_assigned("delete") {
+            qualifiers.push(Qualifier::Deleted);
+        }
+        if is_assigned("default") {
+            qualifiers.push(Qualifier::Defaulted);
+        }
+
+        qualifiers
+    }
+
+    /// Returns the location of a clang entity within its source file.
+    fn location(entity: &clang::Entity) -> Option<Location> {
+        let location = entity.get_location()?.get_spelling_location();
+
+        location.file.map(|file| Location {
+            path: file.get_path(),
+            line: location.line,
+            column: location.column,
+        })
+    }
+
+    /// Returns the storage class a clang entity is declared with.
+    fn storage_class(entity: &clang::Entity) -> Option<StorageClass> {
+        match entity.get_storage_class() {
+            Some(clang::StorageClass::Static) => Some(StorageClass::Static),
+            Some(clang::StorageClass::Extern) => Some(StorageClass::Extern),
+            _ => None,
+        }
+    }
+
+    /// Returns the linkage of a clang entity.
+    fn linkage(entity: &clang::Entity) -> Option<Linkage> {
+        match entity.get_linkage() {
+            Some(clang::Linkage::Internal) => Some(Linkage::Internal),
+            Some(clang::Linkage::External) | Some(clang::Linkage::UniqueExternal) => {
+                Some(Linkage::External)
+            }
+            _ => None,
+        }
+    }
+
     /// Analyzes a clang function entity and returns the connected `EntityType::Function`.
-    fn analyse_clang_function_entity(entity: &clang::Entity) -> Fallible<Option<EntityType>> {
+    fn analyse_clang_function_entity(
+        backend: &dyn LanguageBackend,
+        entity: &clang::Entity,
+    ) -> Fallible<Option<EntityType>> {
         if let Some(entity_name) = entity.get_name() {
             let mut function = Function::new(entity_name);
+            function.usr = entity.get_usr().map(|usr| usr.0);
+            function.access = Self::access(entity);
+            function.storage_class = Self::storage_class(entity);
+            function.linkage = Self::linkage(entity);
+            function.qualifiers = Self::qualifiers(entity);
+            if entity.is_definition() {
+                function.definition = Self::location(entity);
+            } else {
+                function.declaration = Self::location(entity);
+            }
 
             // Set return type.
             if let Some(return_type) = entity.get_type() {
                 function.set_return_type(return_type.get_display_name().as_str())?;
             }
 
-            // Set arguments vector.
-            if let Some(arguments) = entity.get_arguments() {
-                function.set_arguments(&Self::format_arguments(&arguments)?);
+            // Set arguments vector. The arguments of function templates are
+            // only available as children.
+            let arguments = entity.get_arguments().unwrap_or_else(|| {
+                entity
+                    .get_children()
+                    .into_iter()
+                    .filter(|child| child.get_kind() == clang::EntityKind::ParmDecl)
+                    .collect()
+            });
+            let mut arguments = Self::format_arguments(&arguments)?;
+            if entity.is_variadic() {
+                arguments.push(Argument::variadic("...", ArgumentKind::Variadic));
             }
+            function.set_arguments(&arguments);
+            function.template_parameters = Self::template_parameters(entity);
 
             // Set description.
-            if let Some(description) = entity.get_comment() {
-                function.set_description(description.as_str());
+            if let Some(comment) = entity.get_comment() {
+                function.description = Some(backend.extract_description(comment.as_str()));
             }
 
             return Ok(Some(EntityType::Function(function)));
@@ -86,14 +618,41 @@ impl CFamily {
         Ok(None)
     }
 
-    /// Analyzes a generic clang entity and returns the connected `EntityType::Entity`.
-    fn analyse_clang_generic_entity(entity: &clang::Entity) -> Fallible<Option<EntityType>> {
-        if let Some(entity_name) = entity.get_name() {
-            let mut ent = Entity::new(entity_name);
+    /// Analyzes a generic clang entity and returns the connected `EntityType::Entity`
+    /// of the given kind.
+    fn analyse_clang_generic_entity(
+        backend: &dyn LanguageBackend,
+        entity: &clang::Entity,
+        kind: EntityKind,
+    ) -> Fallible<Option<EntityType>> {
+        if let Some(mut entity_name) = entity.get_name() {
+            // Specializations are named with their template arguments.
+            if entity.get_template().is_some() {
+                entity_name = entity.get_display_name().unwrap_or(entity_name);
+            }
+
+            let mut ent = Entity::with_kind(entity_name, kind);
+            ent.access = Self::access(entity);
+            ent.template_parameters = Self::template_parameters(entity);
+
+            // Set base classes and whether the class has pure virtual methods.
+            for child in entity.get_children() {
+                match child.get_kind() {
+                    clang::EntityKind::BaseSpecifier => {
+                        if let Some(base) = child.get_type() {
+                            ent.bases.push(base.get_display_name());
+                        }
+                    }
+                    clang::EntityKind::Method => {
+                        ent.is_abstract |= child.is_pure_virtual_method();
+                    }
+                    _ => {}
+                }
+            }
 
             // Set description.
-            if let Some(description) = entity.get_comment() {
-                ent.set_description(description.as_str());
+            if let Some(comment) = entity.get_comment() {
+                ent.description = Some(backend.extract_description(comment.as_str()));
             }
 
             return Ok(Some(EntityType::Entity(ent)));
@@ -112,14 +671,14 @@ static C_FILE_EXTENSIONS: &[&str] = &["c", "h"];
 pub struct C;
 
 impl C {
-    fn analyse_clang_entity(entity: &clang::Entity) -> Fallible<Option<EntityType>> {
+    fn analyse_clang_entity(&self, entity: &clang::Entity) -> Fallible<Option<EntityType>> {
         let entity_kind = entity.get_kind();
 
         // Search for functions outside the system headers
         if !entity.is_in_system_header() {
             match &entity_kind {
                 clang::EntityKind::FunctionDecl => {
-                    return CFamily::analyse_clang_function_entity(entity);
+                    return CFamily::analyse_clang_function_entity(self, entity);
                 }
                 clang::EntityKind::EnumDecl => {
                     return CFamily::analyse_clang_enum_entity(entity);
@@ -134,47 +693,55 @@ impl C {
     }
 }
 
-impl LanguageType for C {
-    fn file_types() -> &'static [&'static str] {
+impl LanguageBackend for C {
+    fn name(&self) -> &str {
+        "c"
+    }
+
+    fn file_types(&self) -> &[&str] {
         C_FILE_EXTENSIONS
     }
 
-    fn extract_entities<C: LanguageType>(analysis: &Analysis<C>) -> Fallible<()> {
+    fn extract_entities(
+        &self,
+        project_file: &ProjectFile,
+        flags: &[String],
+    ) -> Fallible<Vec<Entity>> {
+        let mut entities = Vec::new();
+
         if let Some(ref clang) = *CLANG {
             let clang_index = clang::Index::new(&clang, false, false);
-            for project_file in analysis.project_files().iter() {
-                info!("Analyzing '{}'", project_file);
-                if let EntityType::Entity(mut index) = EntityType::Entity(Entity::new("")) {
-                    let parsed_path = &clang_index.parser(&project_file.path).parse()?;
-                    let clang_entity = parsed_path.get_entity();
-
-                    // Iterate through the child entities of the current entity
-                    for child in clang_entity.get_children() {
-                        if let Ok(Some(entity)) = Self::analyse_clang_entity(&child) {
-                            index.add_entity::<Entity>(entity);
-                        }
-                    }
-
-                    debug!("{:#?}", index);
-                    project_file.entities_mut().push(index);
+            let mut index = Entity::new("");
+            let parsed_path = &clang_index
+                .parser(&project_file.path)
+                .arguments(&CFamily::arguments("c", flags))
+                .parse()?;
+            let clang_entity = parsed_path.get_entity();
+
+            // Iterate through the child entities of the current entity
+            for child in clang_entity.get_children() {
+                if let Ok(Some(entity)) = self.analyse_clang_entity(&child) {
+                    index.add_entity::<Entity>(entity);
                 }
             }
+
+            entities.push(index);
         }
 
-        Ok(())
+        Ok(entities)
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
 
 /// The file extensions which should be checked for C++ project analysis.
-static CPP_FILE_EXTENSIONS: &[&str] = &["cpp", "hpp"];
+static CPP_FILE_EXTENSIONS: &[&str] = &["cpp", "cc", "cxx", "hpp", "hh", "hxx", "inl", "h"];
 
 #[derive(Default, Clone, Debug)]
 pub struct Cpp;
 
 impl Cpp {
-    fn analyse_clang_entity(entity: &clang::Entity) -> Fallible<Option<EntityType>> {
+    fn analyse_clang_entity(&self, entity: &clang::Entity) -> Fallible<Option<EntityType>> {
         let entity_kind = entity.get_kind();
 
         // Search for functions outside the system headers
@@ -183,14 +750,37 @@ impl Cpp {
                 clang::EntityKind::Constructor
                 | clang::EntityKind::Destructor
                 | clang::EntityKind::Method
-                | clang::EntityKind::FunctionDecl => {
-                    return CFamily::analyse_clang_function_entity(entity);
+                | clang::EntityKind::FunctionDecl
+                | clang::EntityKind::FunctionTemplate => {
+                    return CFamily::analyse_clang_function_entity(self, entity);
                 }
                 clang::EntityKind::EnumDecl => {
                     return CFamily::analyse_clang_enum_entity(entity);
                 }
-                clang::EntityKind::ClassDecl | clang::EntityKind::Namespace => {
-                    return CFamily::analyse_clang_generic_entity(entity);
+                clang::EntityKind::ClassDecl => {
+                    return CFamily::analyse_clang_generic_entity(self, entity, EntityKind::Class);
+                }
+                clang::EntityKind::StructDecl => {
+                    return CFamily::analyse_clang_generic_entity(self, entity, EntityKind::Struct);
+                }
+                clang::EntityKind::UnionDecl => {
+                    return CFamily::analyse_clang_generic_entity(self, entity, EntityKind::Union);
+                }
+                clang::EntityKind::ClassTemplate
+                | clang::EntityKind::ClassTemplatePartialSpecialization => {
+                    let kind = match entity.get_template_kind() {
+                        Some(clang::EntityKind::StructDecl) => EntityKind::Struct,
+                        Some(clang::EntityKind::UnionDecl) => EntityKind::Union,
+                        _ => EntityKind::Class,
+                    };
+                    return CFamily::analyse_clang_generic_entity(self, entity, kind);
+                }
+                clang::EntityKind::Namespace => {
+                    return CFamily::analyse_clang_generic_entity(
+                        self,
+                        entity,
+                        EntityKind::Namespace,
+                    );
                 }
                 _ => {}
             }
@@ -202,14 +792,15 @@ impl Cpp {
     }
 
     fn analyse_clang_entity_tree(
+        &self,
         parent: &mut Entity,
         clang_entity: &clang::Entity,
     ) -> Fallible<()> {
         // Iterate through the child entities of the current entity
         for child in clang_entity.get_children() {
-            if let Ok(Some(entity)) = Self::analyse_clang_entity(&child) {
+            if let Ok(Some(entity)) = self.analyse_clang_entity(&child) {
                 if let Some(added_entity) = parent.add_entity(entity) {
-                    Self::analyse_clang_entity_tree(added_entity, &child)?;
+                    self.analyse_clang_entity_tree(added_entity, &child)?;
                 }
             }
         }
@@ -218,24 +809,138 @@ impl Cpp {
     }
 }
 
-impl LanguageType for Cpp {
-    fn file_types() -> &'static [&'static str] {
+impl LanguageBackend for Cpp {
+    fn name(&self) -> &str {
+        "cpp"
+    }
+
+    fn file_types(&self) -> &[&str] {
         CPP_FILE_EXTENSIONS
     }
 
-    fn extract_entities<Cpp: LanguageType>(analysis: &Analysis<Cpp>) -> Fallible<()> {
+    fn extract_entities(
+        &self,
+        project_file: &ProjectFile,
+        flags: &[String],
+    ) -> Fallible<Vec<Entity>> {
+        let mut entities = Vec::new();
+
         if let Some(ref clang) = *CLANG {
             let clang_index = clang::Index::new(&clang, false, false);
-            for project_file in analysis.project_files().iter() {
-                info!("Analyzing '{}'", project_file);
-                if let EntityType::Entity(mut index) = EntityType::Entity(Entity::new("")) {
-                    let parsed_path = &clang_index.parser(&project_file.path).parse()?;
-                    let clang_entity = parsed_path.get_entity();
+            let mut index = Entity::new("");
+            let parsed_path = &clang_index
+                .parser(&project_file.path)
+                .arguments(&CFamily::arguments("c++", flags))
+                .parse()?;
+            let clang_entity = parsed_path.get_entity();
+
+            self.analyse_clang_entity_tree(&mut index, &clang_entity)?;
+
+            entities.push(index);
+        }
+
+        Ok(entities)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// The file extensions which should be checked for Objective-C and
+/// Objective-C++ project analysis.
+static OBJC_FILE_EXTENSIONS: &[&str] = &["m", "mm", "h"];
+
+#[derive(Default, Clone, Debug)]
+pub struct ObjC;
+
+impl ObjC {
+    /// Returns the name of the class an Objective-C container entity belongs
+    /// to. Categories extend the class they refer to.
+    fn class_name(entity: &clang::Entity) -> Option<String> {
+        match entity.get_kind() {
+            clang::EntityKind::ObjCCategoryDecl | clang::EntityKind::ObjCCategoryImplDecl => entity
+                .get_children()
+                .iter()
+                .find(|child| child.get_kind() == clang::EntityKind::ObjCClassRef)
+                .and_then(|class_ref| class_ref.get_name())
+                .or_else(|| entity.get_name()),
+            _ => entity.get_name(),
+        }
+    }
 
-                    Self::analyse_clang_entity_tree(&mut index, &clang_entity)?;
+    /// Returns the class `Entity` of the given name within the parent. The
+    /// interface, the implementation and the categories of a class share it.
+    fn class_entity<'a>(parent: &'a mut Entity, name: &str) -> Option<&'a mut Entity> {
+        let is_class = |child: &EntityType| match child {
+            EntityType::Entity(class) => class.name == name,
+            _ => false,
+        };
+
+        if !parent.entities.iter().any(is_class) {
+            let class = Entity::with_kind(name, EntityKind::Class);
+            return parent.add_entity(EntityType::Entity(class));
+        }
 
-                    debug!("{:#?}", index);
-                    project_file.entities_mut().push(index);
+        parent
+            .entities
+            .iter_mut()
+            .filter(|child| is_class(child))
+            .filter_map(|child| match child {
+                EntityType::Entity(class) => Some(class),
+                _ => None,
+            })
+            .next()
+    }
+
+    /// Analyzes a clang Objective-C method entity and returns the connected
+    /// `EntityType::Function`, which is named by its selector.
+    fn analyse_clang_method_entity(&self, entity: &clang::Entity) -> Fallible<Option<EntityType>> {
+        let mut method = CFamily::analyse_clang_function_entity(self, entity)?;
+
+        // The type of a method entity is its result type.
+        if let Some(EntityType::Function(function)) = &mut method {
+            function.return_type = entity
+                .get_result_type()
+                .map(|result_type| result_type.get_display_name());
+        }
+
+        Ok(method)
+    }
+
+    /// Analyzes a clang Objective-C interface, implementation or category
+    /// entity and adds its methods to the class entity within the parent.
+    fn analyse_clang_class_entity(
+        &self,
+        parent: &mut Entity,
+        entity: &clang::Entity,
+    ) -> Fallible<()> {
+        let class_name = match Self::class_name(entity) {
+            Some(class_name) => class_name,
+            None => return Ok(()),
+        };
+
+        if let Some(class) = Self::class_entity(parent, &class_name) {
+            if class.description.is_none() {
+                class.description = entity
+                    .get_comment()
+                    .map(|comment| self.extract_description(comment.as_str()));
+            }
+
+            for child in entity.get_children() {
+                match child.get_kind() {
+                    clang::EntityKind::ObjCSuperClassRef => {
+                        if let Some(base) = child.get_name() {
+                            if !class.bases.contains(&base) {
+                                class.bases.push(base);
+                            }
+                        }
+                    }
+                    clang::EntityKind::ObjCInstanceMethodDecl
+                    | clang::EntityKind::ObjCClassMethodDecl => {
+                        if let Some(method) = self.analyse_clang_method_entity(&child)? {
+                            class.add_entity::<Function>(method);
+                        }
+                    }
+                    _ => {}
                 }
             }
         }
@@ -244,55 +949,210 @@ impl LanguageType for Cpp {
     }
 }
 
+impl LanguageBackend for ObjC {
+    fn name(&self) -> &str {
+        "objc"
+    }
+
+    fn file_types(&self) -> &[&str] {
+        OBJC_FILE_EXTENSIONS
+    }
+
+    fn extract_entities(
+        &self,
+        project_file: &ProjectFile,
+        flags: &[String],
+    ) -> Fallible<Vec<Entity>> {
+        let mut entities = Vec::new();
+
+        // Objective-C++ files are distinguished by their `.mm` extension.
+        let language = if project_file.path.extension() == Some(OsStr::new("mm")) {
+            "objective-c++"
+        } else {
+            "objective-c"
+        };
+
+        if let Some(ref clang) = *CLANG {
+            let clang_index = clang::Index::new(&clang, false, false);
+            let mut index = Entity::new("");
+            let parsed_path = &clang_index
+                .parser(&project_file.path)
+                .arguments(&CFamily::arguments(language, flags))
+                .parse()?;
+            let clang_entity = parsed_path.get_entity();
+
+            // Search for classes and functions outside the system headers
+            for child in clang_entity.get_children() {
+                if child.is_in_system_header() {
+                    continue;
+                }
+
+                match child.get_kind() {
+                    clang::EntityKind::ObjCInterfaceDecl
+                    | clang::EntityKind::ObjCImplementationDecl
+                    | clang::EntityKind::ObjCCategoryDecl
+                    | clang::EntityKind::ObjCCategoryImplDecl => {
+                        self.analyse_clang_class_entity(&mut index, &child)?;
+                    }
+                    clang::EntityKind::FunctionDecl => {
+                        if let Some(function) =
+                            CFamily::analyse_clang_function_entity(self, &child)?
+                        {
+                            index.add_entity::<Function>(function);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            entities.push(index);
+        }
+
+        Ok(entities)
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 
 /// The file extensions which should be checked for Python project analysis.
-static PYTHON_FILE_EXTENSIONS: &[&str] = &["py"];
+static PYTHON_FILE_EXTENSIONS: &[&str] = &["py", "pyi"];
+
+/// The file which turns a directory into a Python package.
+static PYTHON_PACKAGE_FILE: &str = "__init__.py";
 
 #[derive(Default, Clone, Debug)]
 pub struct Python;
 
 impl Python {
-    fn extract_function_doc(function: &mut Function, statement: &Statement) {
-        if let Statement::Assignment(ent_v, _) = statement {
-            for ent in ent_v.iter() {
-                if let Expression::String(expr_v) = ent {
-                    for expr in expr_v.iter() {
-                        function.set_description(&expr.content.to_string_lossy());
-                    }
-                }
+    /// Formats an expression back to its Python source representation.
+    fn format_expr(expression: &Expression) -> String {
+        format_module(&[Statement::Expressions(vec![expression.clone()])])
+            .trim()
+            .to_string()
+    }
+
+    /// Creates the `Argument` of a parameter with its optional annotation
+    /// and default value.
+    fn argument(
+        name: &str,
+        annotation: &Option<Expression>,
+        default: &Option<Expression>,
+        kind: ArgumentKind,
+    ) -> Argument {
+        let mut argument =
+            Argument::new(String::from(name), annotation.as_ref().map(Self::format_expr));
+        argument.kind = kind;
+        if let Some(default) = default {
+            argument.set_value(&Self::format_expr(default));
+        }
+
+        argument
+    }
+
+    /// Returns the `Location` of the given byte offset within the content of a file.
+    fn location(path: &Path, content: &str, offset: usize) -> Location {
+        let preceding = &content[..offset];
+        let line_start = preceding.rfind('\n').map_or(0, |newline| newline + 1);
+
+        Location {
+            path: path.to_path_buf(),
+            line: preceding.matches('\n').count() as u32 + 1,
+            column: preceding[line_start..].chars().count() as u32 + 1,
+        }
+    }
+
+    /// Returns the error of invalid syntax at the given byte offset within the
+    /// content of a file.
+    fn syntax_error(path: &Path, content: &str, offset: usize) -> Error {
+        format_err!("{}: Invalid python syntax.", Self::location(path, content, offset))
+    }
+
+    /// Returns the description of a docstring, which is the string the code
+    /// of a module, class or function starts with.
+    fn docstring(&self, code: &[Statement]) -> Option<Description> {
+        if let Some(Statement::Assignment(expressions, assigned)) = code.first() {
+            if let (Some(Expression::String(strings)), true) =
+                (expressions.first(), assigned.is_empty())
+            {
+                let docstring: String = strings
+                    .iter()
+                    .map(|string| string.content.to_string_lossy())
+                    .collect();
+                return Some(self.extract_description(&docstring));
             }
         }
+
+        None
     }
 
-    fn analyse_statement(entity: &mut Entity, statement: &Statement) -> Fallible<()> {
+    fn analyse_statement(&self, entity: &mut Entity, statement: &Statement) -> Fallible<()> {
         if let Statement::Compound(ent_box) = statement {
-            match Box::leak((*ent_box).clone()) {
+            match &**ent_box {
                 // Statement is a statement definition
                 CompoundStatement::Funcdef(expr) => {
                     let mut function: Function = Function::new(expr.name.as_str());
+                    function.language = Some(String::from(self.name()));
 
                     // Split arguments and add them to the function
+                    let parameters = &expr.parameters;
                     let mut arguments: Vec<Argument> = Vec::new();
-                    for arg in &expr.parameters.positional_args {
-                        arguments.push(Argument::new(arg.0.as_str(), None));
+                    for arg in &parameters.positional_args {
+                        arguments.push(Self::argument(
+                            &arg.0,
+                            &arg.1,
+                            &arg.2,
+                            ArgumentKind::Single,
+                        ));
+                    }
+                    if let StarParams::Named((name, annotation)) = &parameters.star_args {
+                        arguments.push(Self::argument(
+                            name,
+                            annotation,
+                            &None,
+                            ArgumentKind::Variadic,
+                        ));
+                    }
+                    for arg in &parameters.keyword_args {
+                        arguments.push(Self::argument(
+                            &arg.0,
+                            &arg.1,
+                            &arg.2,
+                            ArgumentKind::KeywordOnly,
+                        ));
+                    }
+                    if let Some((name, annotation)) = &parameters.star_kwargs {
+                        arguments.push(Self::argument(
+                            name,
+                            annotation,
+                            &None,
+                            ArgumentKind::Keywords,
+                        ));
                     }
                     function.set_arguments(&arguments);
 
-                    if let Some(mut function_inst) =
-                        entity.add_entity(EntityType::Function(function))
-                    {
-                        Self::extract_function_doc(&mut function_inst, &expr.code[0]);
+                    if let Some(return_type) = &expr.return_type {
+                        function.return_type = Some(Self::format_expr(return_type));
                     }
+
+                    function.decorators = expr
+                        .decorators
+                        .iter()
+                        .map(|decorator| decorator.name.join("."))
+                        .collect();
+                    function.is_async = expr.async;
+                    function.description = self.docstring(&expr.code);
+
+                    entity.add_entity::<Function>(EntityType::Function(function));
                 }
 
                 // Statement is a class definition
                 CompoundStatement::Classdef(expr) => {
-                    if let Some(ref mut class_entity) =
-                        entity.add_entity(EntityType::Entity(Entity::new(expr.name.as_str())))
+                    let mut class = Entity::with_kind(expr.name.as_str(), EntityKind::Class);
+                    class.description = self.docstring(&expr.code);
+                    if let Some(ref mut class_entity) = entity.add_entity(EntityType::Entity(class))
                     {
                         for code in &expr.code {
-                            Self::analyse_statement(class_entity, &code)?;
+                            self.analyse_statement(class_entity, &code)?;
                         }
                     }
                 }
@@ -304,35 +1164,115 @@ impl Python {
     }
 }
 
-impl LanguageType for Python {
-    fn file_types() -> &'static [&'static str] {
-        PYTHON_FILE_EXTENSIONS
+impl LanguageBackend for Python {
+    fn name(&self) -> &str {
+        "python"
     }
 
-    fn extract_entities<Python: LanguageType>(analysis: &Analysis<Python>) -> Fallible<()> {
-        for project_file in analysis.project_files().iter() {
-            info!("Analyzing '{}'", project_file);
+    fn file_types(&self) -> &[&str] {
+        PYTHON_FILE_EXTENSIONS
+    }
 
-            // Parse file to string
-            let mut file = File::open(&project_file.path)?;
-            let mut content = String::new();
-            file.read_to_string(&mut content)?;
+    /// Python names are dotted paths like `module.Class.method`.
+    fn scope_separator(&self) -> &str {
+        "."
+    }
 
-            if let EntityType::Entity(mut index) = EntityType::Entity(Entity::new("")) {
-                match file_input(make_strspan(content.as_str())) {
-                    Ok(ast) => {
-                        for entity in ast.1.iter() {
-                            Self::analyse_statement(&mut index, entity)?;
-                        }
+    /// Returns the dotted module path of a Python file. Without a root, or
+    /// for files outside of it, the file is imported relative to the first
+    /// parent directory which is no package. The module of a package is the
+    /// package itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::path::Path;
+    /// use thinlinelib::language_type::{LanguageBackend, Python};
+    ///
+    /// let path = Path::new("src").join("package").join("module.py");
+    /// let module_path = Python.module_path(&path, Some(Path::new("src"))).unwrap();
+    ///
+    /// assert_eq!(module_path.name, "package.module");
+    /// assert_eq!(module_path.root, Path::new("src"));
+    ///
+    /// let path = Path::new("src").join("package").join("__init__.py");
+    /// assert_eq!(Python.module_path(&path, Some(Path::new("src"))).unwrap().name, "package");
+    /// ```
+    fn module_path(&self, path: &Path, root: Option<&Path>) -> Option<ModulePath> {
+        let root = match root.filter(|root| path.starts_with(root)) {
+            Some(root) => root.to_path_buf(),
+            None => {
+                let mut root = path.parent()?.to_path_buf();
+                while root.join(PYTHON_PACKAGE_FILE).is_file() {
+                    match root.parent() {
+                        Some(parent) => root = parent.to_path_buf(),
+                        None => break,
                     }
-                    Err(_) => bail!("Unable to create python AST."),
                 }
-                debug!("{:#?}", index);
-                project_file.entities_mut().push(index);
+                root
             }
+        };
+
+        let mut names: Vec<String> = path
+            .strip_prefix(&root)
+            .ok()?
+            .parent()?
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy().into_owned())
+            .collect();
+        if path.file_name() != Some(OsStr::new(PYTHON_PACKAGE_FILE)) {
+            names.push(path.file_stem()?.to_string_lossy().into_owned());
+        }
+        if names.is_empty() {
+            return None;
         }
 
-        Ok(())
+        Some(ModulePath {
+            root,
+            name: names.join("."),
+        })
+    }
+
+    fn extract_entities(
+        &self,
+        project_file: &ProjectFile,
+        _flags: &[String],
+    ) -> Fallible<Vec<Entity>> {
+        // Parse file to string
+        let mut file = File::open(&project_file.path)?;
+        let mut content = String::new();
+        file.read_to_string(&mut content)?;
+
+        // The entities of a Python file are contained within its module.
+        let module_name = project_file
+            .path
+            .file_stem()
+            .and_then(OsStr::to_str)
+            .ok_or_else(|| err_msg("Unable to stringify the python module name."))?;
+        let mut index = Entity::with_kind(module_name, EntityKind::Module);
+        let ast = match file_input(make_strspan(content.as_str())) {
+            Ok((rest, ast)) => {
+                // The parser stops at the first statement it is unable to parse.
+                if !rest.fragment.trim().is_empty() {
+                    return Err(Self::syntax_error(&project_file.path, &content, rest.offset));
+                }
+                ast
+            }
+            Err(ParseError::Error(Context::Code(rest, _)))
+            | Err(ParseError::Failure(Context::Code(rest, _))) => {
+                return Err(Self::syntax_error(&project_file.path, &content, rest.offset));
+            }
+            Err(ParseError::Incomplete(_)) => {
+                return Err(Self::syntax_error(&project_file.path, &content, content.len()));
+            }
+        };
+
+        index.description = self.docstring(&ast);
+        for entity in &ast {
+            self.analyse_statement(&mut index, entity)?;
+        }
+
+        Ok(vec![index])
     }
 }
 
@@ -340,54 +1280,60 @@ impl LanguageType for Python {
 
 #[cfg(test)]
 mod c {
-    use super::{Analysis, LanguageType, C, C_FILE_EXTENSIONS};
+    use super::{LanguageBackend, C, C_FILE_EXTENSIONS};
 
     #[test]
-    fn new() {
-        let analysis: Analysis<C> = Analysis::new();
-
-        assert_eq!(analysis.file_types, C_FILE_EXTENSIONS);
-        assert_eq!(analysis.project_files().len(), 0);
+    fn name() {
+        assert_eq!(C.name(), "c");
     }
 
     #[test]
     fn file_types() {
-        assert_eq!(C::file_types(), C_FILE_EXTENSIONS);
+        assert_eq!(C.file_types(), C_FILE_EXTENSIONS);
     }
 }
 
 #[cfg(test)]
 mod cpp {
-    use super::{Analysis, Cpp, LanguageType, CPP_FILE_EXTENSIONS};
+    use super::{Cpp, LanguageBackend, CPP_FILE_EXTENSIONS};
+
+    #[test]
+    fn name() {
+        assert_eq!(Cpp.name(), "cpp");
+    }
 
     #[test]
-    fn new() {
-        let analysis: Analysis<Cpp> = Analysis::new();
+    fn file_types() {
+        assert_eq!(Cpp.file_types(), CPP_FILE_EXTENSIONS);
+    }
+}
+
+#[cfg(test)]
+mod objc {
+    use super::{LanguageBackend, ObjC, OBJC_FILE_EXTENSIONS};
 
-        assert_eq!(analysis.file_types, CPP_FILE_EXTENSIONS);
-        assert_eq!(analysis.project_files().len(), 0);
+    #[test]
+    fn name() {
+        assert_eq!(ObjC.name(), "objc");
     }
 
     #[test]
     fn file_types() {
-        assert_eq!(Cpp::file_types(), CPP_FILE_EXTENSIONS);
+        assert_eq!(ObjC.file_types(), OBJC_FILE_EXTENSIONS);
     }
 }
 
 #[cfg(test)]
 mod python {
-    use super::{Analysis, LanguageType, Python, PYTHON_FILE_EXTENSIONS};
+    use super::{LanguageBackend, Python, PYTHON_FILE_EXTENSIONS};
 
     #[test]
-    fn new() {
-        let analysis: Analysis<Python> = Analysis::new();
-
-        assert_eq!(analysis.file_types, PYTHON_FILE_EXTENSIONS);
-        assert_eq!(analysis.project_files().len(), 0);
+    fn name() {
+        assert_eq!(Python.name(), "python");
     }
 
     #[test]
     fn file_types() {
-        assert_eq!(Python::file_types(), PYTHON_FILE_EXTENSIONS);
+        assert_eq!(Python.file_types(), PYTHON_FILE_EXTENSIONS);
     }
 }
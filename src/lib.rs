@@ -10,43 +10,74 @@ extern crate glob;
 extern crate lazy_static;
 #[macro_use]
 extern crate log;
+extern crate nom;
+extern crate notify;
 extern crate python_parser;
+extern crate rayon;
 extern crate regex;
 extern crate run_script;
 #[macro_use]
 extern crate serde_derive;
 extern crate serde;
+extern crate serde_json;
+extern crate serde_yaml;
 extern crate slog_envlogger;
 extern crate snapshot;
 extern crate walkdir;
 extern crate yaml_rust;
 
 pub mod analysis;
+pub mod cache;
+pub mod changes;
+pub mod cmake;
+pub mod compiler;
+pub mod dsl;
+pub mod dump;
 pub mod entity;
+pub mod execution;
 pub mod language_type;
 pub mod project_parameters;
 pub mod stubs;
 pub mod synthesis;
 pub mod value_parser;
 
-use analysis::{Analysis, ProjectFile};
-use directories::BaseDirs;
-use failure::{err_msg, Fallible};
-use language_type::LanguageType;
-use project_parameters::ProjectParameters;
-use std::path::PathBuf;
+use analysis::{Analysis, ProjectFile, Strictness, HEADER_FILE_EXTENSIONS};
+use cache::AnalysisCache;
+use changes::Changes;
+use cmake::CMakeProject;
+use compiler::CompilerDriver;
+use dump::DumpFormat;
+use entity::Entity;
+use execution::TestResult;
+use failure::Fallible;
+use language_type::LanguageBackend;
+use notify::{DebouncedEvent, RecursiveMode, Watcher};
+use project_parameters::{BuildBackend, ProjectParameters};
+use std::{
+    collections::HashMap, ffi::OsStr, fs::{create_dir_all, File}, io::Write,
+    path::{Path, PathBuf}, sync::mpsc::channel, time::Duration,
+};
 use synthesis::*;
 
 ////////////////////////////////////////////////////////////////////////////////
 
 static DEFAULT_ENV_YML: &str = "./stubs/environment/env_stubs.yml";
+static DEFAULT_TLG_HEADER: &str = "./stubs/system/tlg.h";
+static CMAKE_PROJECT_NAME: &str = "thinline_tests";
+
+/// The delay in milliseconds file changes are gathered before reanalyzing
+/// the project in watch mode.
+static WATCH_DELAY_MS: u64 = 500;
+
+/// The directory within the output directory the previous versions of
+/// changed files are written to.
+static CHANGED_SINCE_DIR: &str = "changed_since";
+
+////////////////////////////////////////////////////////////////////////////////
 
 #[derive(Default, Debug)]
 /// Global structure representing the `Thinline` lib.
-pub struct Thinline<T>
-where
-    T: LanguageType,
-{
+pub struct Thinline {
     /// The target project directory.
     pub project_dir: PathBuf,
 
@@ -54,26 +85,73 @@ where
     pub project_parameters: ProjectParameters,
 
     /// The structure holding the analysis_c data.
-    analysis: Analysis<T>,
+    analysis: Analysis,
+
+    /// The structures holding the synthesized testdata of each language.
+    syntheses: Vec<(String, Synthesis)>,
+
+    /// Indicator whether the analysis results are cached in the output directory.
+    use_cache: bool,
 
-    /// The structure holding the synthesized testdata.
-    synthesis: Synthesis<T>,
+    /// The git revision the analysis is limited to the changes since.
+    changed_since: Option<String>,
+
+    /// The complete entity trees of the last analysis of each project file.
+    entity_trees: HashMap<PathBuf, Vec<Entity>>,
 }
 
-impl<T> Thinline<T>
-where
-    T: LanguageType,
-{
+impl Thinline {
     /// Creates an instance of the lib containing `Thinline`s functionality.
     pub fn new<P: Into<PathBuf>>(project_dir: P) -> Self {
         Self {
             project_dir: project_dir.into(),
             project_parameters: ProjectParameters::new(),
-            analysis: Analysis::new(),
-            synthesis: Synthesis::new(),
+            analysis: Analysis::default(),
+            syntheses: Vec::new(),
+            use_cache: true,
+            changed_since: None,
+            entity_trees: HashMap::new(),
         }
     }
 
+    /// Enables or disables the cache of the analysis results, which is
+    /// stored in the output directory and enabled by default.
+    pub fn set_cache(&mut self, use_cache: bool) {
+        self.use_cache = use_cache;
+    }
+
+    /// Limits the analysis, synthesis and execution to the entities which
+    /// changed since the given git revision of the project repository.
+    pub fn set_changed_since<S: Into<String>>(&mut self, revision: S) {
+        self.changed_since = Some(revision.into());
+    }
+
+    /// Registers a language backend, replacing a built-in one with the same name.
+    pub fn register_backend(&mut self, backend: Box<dyn LanguageBackend>) {
+        self.analysis.backends_mut().register(backend);
+    }
+
+    /// Restricts the analysis to the given languages. By default all
+    /// languages of the project config are analyzed.
+    pub fn set_languages<S: AsRef<str>>(&mut self, languages: &[S]) {
+        self.analysis.languages = languages
+            .iter()
+            .map(|language| String::from(language.as_ref()))
+            .collect();
+    }
+
+    /// Sets the number of project files analyzed in parallel, where `0`
+    /// means one per logical CPU.
+    pub fn set_jobs(&mut self, jobs: usize) {
+        self.analysis.jobs = jobs;
+    }
+
+    /// Sets how project files which fail to be analyzed are handled, by
+    /// default they are reported as warning and skipped.
+    pub fn set_strictness(&mut self, strictness: Strictness) {
+        self.analysis.strictness = strictness;
+    }
+
     /// Starts the analysis of the target project.
     pub fn analyze(&mut self, thinline_cfg: &str, build: bool) -> Fallible<()> {
         // Parses the project config
@@ -90,65 +168,104 @@ where
         Ok(())
     }
 
-    /// Traverses upwared through the `seed_dir`s parent directories until
-    /// `thinline.yml` configuration exists in dir or home directory is reached.
-    fn find_thinline_config_dir<'a>(seed_dir: &'a PathBuf, config_name: &str) -> Option<PathBuf> {
-        if let Some(base_dirs) = BaseDirs::new() {
-            let home_dir = base_dirs.home_dir();
-            let mut project_config = seed_dir.clone();
+    /// Parses configuration from the given config yaml.
+    fn parse_project_config(&mut self, config_name: &str) -> Fallible<()> {
+        self.project_parameters =
+            ProjectParameters::from_project_dir(&self.project_dir, config_name)?;
 
-            loop {
-                if project_config == home_dir || project_config.join(config_name).is_file() {
-                    trace!("Config dir: {:?}", project_config);
-                    break;
-                }
+        debug!("{:#?}", self.project_parameters);
 
-                if let Some(parent) = project_config.clone().as_path().parent() {
-                    project_config = parent.to_path_buf();
-                } else {
-                    return None;
-                }
-            }
-
-            return Some(project_config.join(config_name));
+        // The analyzed languages have to be part of the project config.
+        if self.analysis.languages.is_empty() {
+            self.analysis.languages = self.project_parameters.languages.clone();
+        } else if let Some(language) = self
+            .analysis
+            .languages
+            .iter()
+            .find(|language| !self.project_parameters.languages.contains(language))
+        {
+            return Err(format_err!(
+                "The language '{}' differs from the language(s) '{}' of the project config.",
+                language,
+                self.project_parameters.languages.join(", ")
+            ));
         }
 
-        None
-    }
+        for language in &self.analysis.languages {
+            if self.analysis.backends().by_name(language).is_none() {
+                return Err(format_err!("Unsupported language '{}'.", language));
+            }
+        }
 
-    /// Parses configuration from the given config yaml.
-    fn parse_project_config(&mut self, config_name: &str) -> Fallible<()> {
-        if let Some(project_config) = Self::find_thinline_config_dir(&self.project_dir, config_name)
-        {
-            if !project_config.exists() || !project_config.is_file() {
+        // The file types of the project config override the default ones.
+        for (language, file_types) in &self.project_parameters.file_types {
+            if self.analysis.backends().by_name(language).is_none() {
                 return Err(format_err!(
-                    "The given project config file '{}' does not exist or is a directory.",
-                    project_config
-                        .to_str()
-                        .ok_or_else(|| err_msg("Unable to stringify project config file."))?
+                    "Unsupported language '{}' within the file types.",
+                    language
                 ));
             }
-
-            self.project_parameters = ProjectParameters::parse(
-                project_config
-                    .to_str()
-                    .ok_or_else(|| err_msg("Unable to stringify project config file."))?,
-            )?;
-
-            debug!("{:#?}", self.project_parameters);
-        } else {
-            return Err(format_err!("Unable to get project dir parent for"));
+            self.analysis
+                .backends_mut()
+                .set_file_types(language, file_types);
         }
 
         Ok(())
     }
 
     /// Analyzes the project which should be tested.
-    fn analyze_project(&self) -> Fallible<()> {
+    fn analyze_project(&mut self) -> Fallible<()> {
         if let Some(project_path_s) = self.project_dir.to_str() {
             info!("Starting project analysis at '{}'", project_path_s);
         }
 
+        // The include directories, the defines of the compiler flags and the
+        // analysis flags are passed to the parsers. Other compiler flags only
+        // apply to building the tests, e.g. `-std=c++11` breaks parsing C files.
+        self.analysis.flags = self
+            .project_paths(&self.project_parameters.include_dirs)
+            .iter()
+            .map(|include_dir| format!("-I{}", include_dir.display()))
+            .chain(
+                self.project_parameters
+                    .compiler
+                    .flags
+                    .iter()
+                    .filter(|flag| flag.starts_with("-D") || flag.starts_with("-U"))
+                    .cloned(),
+            )
+            .chain(self.project_parameters.analysis_flags.iter().cloned())
+            .collect();
+        self.analysis.module_root = self
+            .project_parameters
+            .module_root
+            .as_ref()
+            .map(|module_root| self.project_base_dir().join(module_root));
+
+        self.collect_project_files()?;
+
+        let changes = match &self.changed_since {
+            Some(revision) => Some(Changes::since(&self.project_base_dir(), revision)?),
+            None => None,
+        };
+
+        self.extract_entities(changes.as_ref())
+    }
+
+    /// Analyzes the project again after the given files changed. Only the
+    /// entities affected by the changes are kept for the synthesis.
+    pub fn reanalyze(&mut self, changed_files: &[PathBuf]) -> Fallible<()> {
+        info!("Reanalyzing {} changed file(s)", changed_files.len());
+
+        self.analysis.project_files_mut().clear();
+        self.syntheses.clear();
+
+        self.collect_project_files()?;
+        self.extract_entities(Some(&Changes::from_files(changed_files)))
+    }
+
+    /// Collects the project files of the analyzed languages.
+    fn collect_project_files(&self) -> Fallible<()> {
         if self.project_dir.is_dir() {
             // Project path is a directory, thus it is neccessay to traverse to the project
             // and collect all the sources.
@@ -157,35 +274,457 @@ where
         }
 
         if self.project_dir.is_file() {
-            if let Some(ext) = self.project_dir.extension() {
-                // Project path is a file and has the right extension.
-                if T::file_types().contains(
-                    &ext.to_str()
-                        .ok_or_else(|| err_msg("Unable to stringify file extension."))?,
-                ) {
-                    // Push it to the project file vector for analyzing purposes.
-                    self.analysis
-                        .project_files_mut()
-                        .push(ProjectFile::new(&self.project_dir));
-                }
+            // Project path is a file and has the extension of an analyzed language.
+            if self.analysis.backend(&self.project_dir).is_some() {
+                // Push it to the project file vector for analyzing purposes.
+                self.analysis.add_project_file(&self.project_dir);
             }
         }
 
+        Ok(())
+    }
+
+    /// Extracts the entities of the collected project files. When changes
+    /// are given, the analysis is limited to the entities affected by them.
+    fn extract_entities(&mut self, changes: Option<&Changes>) -> Fallible<()> {
+        // Only the changed files and the files including them are analyzed.
+        if let Some(changes) = changes {
+            let project_files: Vec<PathBuf> = self
+                .analysis
+                .project_files()
+                .iter()
+                .map(|project_file| project_file.path.clone())
+                .collect();
+            let affected_files = changes.affected_files(&project_files);
+
+            self.analysis
+                .project_files_mut()
+                .retain(|project_file| affected_files.contains(&project_file.path));
+        }
+
+        let cache_file = self.output_dir().join(cache::CACHE_FILE_NAME);
+        if self.use_cache {
+            self.analysis.set_cache(AnalysisCache::load(&cache_file));
+        }
+
         self.analysis.extract_entities()?;
 
+        if let Some(mut cache) = self.analysis.take_cache() {
+            cache.save(&cache_file)?;
+        }
+
+        // The complete entity trees are kept to find the changed entities
+        // when the project is reanalyzed.
+        let mut previous_entity_trees = HashMap::new();
+        for project_file in self.analysis.project_files().iter() {
+            let entities = project_file.entities().clone();
+            if let Some(previous_entities) =
+                self.entity_trees.insert(project_file.path.clone(), entities)
+            {
+                previous_entity_trees.insert(project_file.path.clone(), previous_entities);
+            }
+        }
+
+        if let Some(changes) = changes {
+            self.retain_changed_entities(changes, &previous_entity_trees)?;
+        }
+
+        Ok(())
+    }
+
+    /// Removes the entities of the changed project files whose signature and
+    /// description are equal to the previous ones. These are taken from the
+    /// revision of the changes or, without revision, the given entity trees.
+    /// Files which are only affected by including changed files are kept.
+    fn retain_changed_entities(
+        &self,
+        changes: &Changes,
+        previous_entity_trees: &HashMap<PathBuf, Vec<Entity>>,
+    ) -> Fallible<()> {
+        for project_file in self.analysis.project_files().iter() {
+            if !changes.is_changed(&project_file.path) {
+                continue;
+            }
+
+            let previous_entities = if changes.revision.is_empty() {
+                previous_entity_trees.get(&project_file.path).cloned()
+            } else {
+                self.revision_entities(changes, project_file)?
+            };
+
+            // Files which did not exist before are completely new.
+            if let Some(previous_entities) = previous_entities {
+                changes::retain_changed(&mut project_file.entities_mut(), &previous_entities);
+            }
+        }
+
         Ok(())
     }
 
+    /// Extracts the entities of the given project file at the revision of
+    /// the changes, or returns `None` when it did not exist.
+    fn revision_entities(
+        &self,
+        changes: &Changes,
+        project_file: &ProjectFile,
+    ) -> Fallible<Option<Vec<Entity>>> {
+        let backend = match self.analysis.backend(&project_file.path) {
+            Some(backend) => backend,
+            None => return Ok(None),
+        };
+
+        let previous_content = match changes.previous_content(&project_file.path)? {
+            Some(previous_content) => previous_content,
+            None => return Ok(None),
+        };
+
+        // The previous version is written to the output directory, keeping
+        // its file name and including the headers of its original location.
+        let previous_path = project_file
+            .path
+            .strip_prefix(&self.project_base_dir())
+            .ok()
+            .or_else(|| project_file.path.file_name().map(Path::new))
+            .map(|relative_path| self.output_dir().join(CHANGED_SINCE_DIR).join(relative_path))
+            .ok_or_else(|| {
+                format_err!("Invalid project file '{}'.", project_file.path.display())
+            })?;
+        if let Some(parent) = previous_path.parent() {
+            create_dir_all(parent)?;
+        }
+        File::create(&previous_path)?.write_all(previous_content.as_bytes())?;
+
+        let mut flags = self.analysis.flags.clone();
+        if let Some(parent) = project_file.path.parent() {
+            flags.push(format!("-I{}", parent.display()));
+        }
+
+        let mut entities = backend.extract_entities(&ProjectFile::new(&previous_path), &flags)?;
+        for entity in &mut entities {
+            entity.qualify_with(backend.scope_separator());
+        }
+
+        Ok(Some(entities))
+    }
+
+    /// Watches the analysis directories of the project and reanalyzes it
+    /// whenever project files change. The given function is called after
+    /// every reanalysis, e.g. to synthesize and execute the affected tests.
+    /// Failing cycles are logged and do not stop watching.
+    pub fn watch<F>(&mut self, mut on_change: F) -> Fallible<()>
+    where
+        F: FnMut(&mut Self) -> Fallible<()>,
+    {
+        let (sender, receiver) = channel();
+        let mut watcher = notify::watcher(sender, Duration::from_millis(WATCH_DELAY_MS))?;
+
+        if self.project_dir.is_file() {
+            watcher.watch(&self.project_dir, RecursiveMode::NonRecursive)?;
+        } else {
+            for source_dir in self.project_paths(&self.project_parameters.source_dirs) {
+                watcher.watch(&source_dir, RecursiveMode::Recursive)?;
+            }
+        }
+        info!("Watching '{}' for changes", self.project_dir.display());
+
+        let output_dir = self
+            .output_dir()
+            .canonicalize()
+            .unwrap_or_else(|_| self.output_dir());
+
+        loop {
+            // Events arriving at once are handled within the same cycle.
+            let mut events = vec![receiver.recv()?];
+            events.extend(receiver.try_iter());
+
+            let mut changed_files = Vec::new();
+            for event in events {
+                match event {
+                    DebouncedEvent::Create(path)
+                    | DebouncedEvent::Write(path)
+                    | DebouncedEvent::Remove(path)
+                    | DebouncedEvent::Rename(_, path) => changed_files.push(path),
+                    DebouncedEvent::Error(err, _) => warn!("Unable to watch files: {}", err),
+                    _ => {}
+                }
+            }
+
+            // Files written by thinline itself are ignored.
+            changed_files.retain(|path| {
+                !path.starts_with(&output_dir) && self.analysis.backend(path).is_some()
+            });
+            changed_files.sort();
+            changed_files.dedup();
+            if changed_files.is_empty() {
+                continue;
+            }
+
+            if let Err(err) = self
+                .reanalyze(&changed_files)
+                .and_then(|_| on_change(self))
+            {
+                error!("{}", err);
+            }
+        }
+    }
+
     /// Starts the synthesis of the target projects test files.
     pub fn synthesize<P: Into<PathBuf>>(&mut self, base_path: P) -> Fallible<()> {
-        self.synthesis.parse_stubs(
-            DEFAULT_ENV_YML,
-            self.project_parameters.test_env.as_str(),
-            &base_path.into(),
+        let base_path = base_path.into();
+        let mut syntheses = Vec::new();
+
+        // The tests of each language are synthesized on their own, since
+        // they are written to their own output directories.
+        for language in &self.analysis.languages {
+            let mut synthesis = Synthesis::new();
+            synthesis.project_dir = Some(self.project_base_dir());
+            synthesis.parse_stubs(
+                DEFAULT_ENV_YML,
+                self.project_parameters.test_env.as_str(),
+                &base_path,
+            )?;
+
+            for file in self.analysis.project_files().iter() {
+                if self.is_of_language(&file.path, language) {
+                    synthesis.process_testfile(file)?;
+                }
+            }
+
+            // Writes the test project only if there is something to test.
+            if !synthesis.test_files.is_empty() {
+                self.write_test_project(language, &synthesis)?;
+            }
+
+            syntheses.push((language.clone(), synthesis));
+        }
+        self.syntheses = syntheses;
+
+        Ok(())
+    }
+
+    /// Returns whether the given project file is analyzed by the backend of
+    /// the given language.
+    fn is_of_language(&self, path: &Path, language: &str) -> bool {
+        self.analysis
+            .backend(path)
+            .map_or(false, |backend| backend.name() == language)
+    }
+
+    /// Returns the directory of the target project. When the project path
+    /// is a file, this is the directory containing it.
+    fn project_base_dir(&self) -> PathBuf {
+        if self.project_dir.is_file() {
+            if let Some(parent) = self.project_dir.parent() {
+                return parent.to_path_buf();
+            }
+        }
+
+        self.project_dir.clone()
+    }
+
+    /// Returns the directory where the synthesized test project is written to.
+    pub fn output_dir(&self) -> PathBuf {
+        self.project_base_dir().join(&self.project_parameters.output_dir)
+    }
+
+    /// Returns the directory where the synthesized tests of the given
+    /// language are written to. The languages of mixed projects are written
+    /// to their own subdirectories, so their test files, headers and build
+    /// files do not overwrite each other.
+    pub fn test_output_dir(&self, language: &str) -> PathBuf {
+        if self.analysis.languages.len() > 1 {
+            self.output_dir().join(language)
+        } else {
+            self.output_dir()
+        }
+    }
+
+    /// Writes the synthesized test files of the given language together with
+    /// the header including its project headers and, for the CMake backend,
+    /// a `CMakeLists.txt` building them. Tests of interpreted test
+    /// environments are written without them, while tests which are only
+    /// generated are written without the `CMakeLists.txt`.
+    fn write_test_project(&self, language: &str, synthesis: &Synthesis) -> Fallible<()> {
+        let output_dir = self.test_output_dir(language);
+
+        if synthesis.stubs().interpreted {
+            return synthesis.write_test_files(&output_dir);
+        }
+
+        let headers: Vec<PathBuf> = self
+            .analysis
+            .project_files()
+            .iter()
+            .map(|file| &file.path)
+            .filter(|path| self.is_of_language(path, language))
+            .filter(|path| {
+                path.extension()
+                    .and_then(OsStr::to_str)
+                    .map_or(false, |ext| HEADER_FILE_EXTENSIONS.contains(&ext))
+            })
+            .map(|path| path.canonicalize().unwrap_or_else(|_| path.clone()))
+            .collect();
+
+        synthesis.write_tlg_header(
+            DEFAULT_TLG_HEADER,
+            &output_dir,
+            &headers,
+            self.project_parameters.tlg_prefix.as_ref().map(String::as_str),
+            self.project_parameters.tlg_suffix.as_ref().map(String::as_str),
         )?;
+        synthesis.write_test_files(&output_dir)?;
+
+        if self.project_parameters.build_backend == BuildBackend::CMake
+            && !synthesis.stubs().generate_only
+        {
+            self.cmake_project(synthesis).write(&output_dir)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the paths of the given project relative paths.
+    fn project_paths(&self, paths: &[String]) -> Vec<PathBuf> {
+        let project_base_dir = self.project_base_dir();
+        paths
+            .iter()
+            .map(|path| project_base_dir.join(path))
+            .collect()
+    }
+
+    /// Returns the `CMakeProject` building the given synthesized tests.
+    fn cmake_project(&self, synthesis: &Synthesis) -> CMakeProject {
+        let mut cmake_project = CMakeProject::new(CMAKE_PROJECT_NAME, self.project_base_dir());
+        cmake_project.test_package = synthesis.stubs().cmake_package.clone();
+        cmake_project.test_libraries = synthesis.stubs().cmake_libraries.clone();
+        cmake_project.include_dirs = self.project_parameters.include_dirs.clone();
+        cmake_project.lib_paths = self.project_parameters.lib_paths.clone();
+        cmake_project.test_sources = synthesis
+            .test_files
+            .iter()
+            .map(|test_file| test_file.path.clone())
+            .collect();
+
+        cmake_project
+    }
+
+    /// Builds the synthesized tests with the configured build backend.
+    /// Returns the paths of the built test executables, which are none for
+    /// interpreted test environments and those whose tests are only generated.
+    pub fn build_tests(&self) -> Fallible<Vec<PathBuf>> {
+        let mut executables = Vec::new();
+
+        for (language, synthesis) in &self.syntheses {
+            if !synthesis.test_files.is_empty() {
+                executables.extend(self.build_language_tests(language, synthesis)?);
+            }
+        }
+
+        Ok(executables)
+    }
+
+    /// Builds the given synthesized tests of a language within its output
+    /// directory.
+    fn build_language_tests(
+        &self,
+        language: &str,
+        synthesis: &Synthesis,
+    ) -> Fallible<Vec<PathBuf>> {
+        let output_dir = self.test_output_dir(language);
+
+        if synthesis.stubs().interpreted {
+            return Ok(Vec::new());
+        }
+        if synthesis.stubs().generate_only {
+            info!("The tests of the test environment are only generated, not built.");
+            return Ok(Vec::new());
+        }
+
+        match self.project_parameters.build_backend {
+            BuildBackend::CMake => self.cmake_project(synthesis).build(&output_dir),
+            BuildBackend::Compiler => {
+                let mut compiler_driver = CompilerDriver::new(&self.project_parameters.compiler);
+                compiler_driver.include_dirs =
+                    self.project_paths(&self.project_parameters.include_dirs);
+                compiler_driver.lib_paths = self.project_paths(&self.project_parameters.lib_paths);
+                compiler_driver.libraries = synthesis.stubs().compiler_libraries.clone();
+
+                compiler_driver.build(&output_dir, &synthesis.test_files)
+            }
+        }
+    }
+
+    /// Executes the given test executables and returns their results.
+    pub fn execute_tests(&self, executables: &[PathBuf]) -> Fallible<Vec<TestResult>> {
+        execution::execute(executables)
+    }
+
+    /// Returns the analyzed entities of every project file as indented tree.
+    pub fn entity_tree(&self) -> String {
+        let mut lines = Vec::new();
+
+        for project_file in self.analysis.project_files().iter() {
+            lines.push(project_file.to_string());
+            for entity in project_file.entities().iter() {
+                lines.extend(entity.tree_lines(1));
+            }
+        }
+
+        lines.join("\n")
+    }
+
+    /// Returns the analyzed entities of every project file, including their
+    /// descriptions and the parsed test DSL, in the given format.
+    pub fn dump_analysis(&self, format: &DumpFormat) -> Fallible<String> {
+        dump::dump(&self.analysis.project_files(), format)
+    }
+
+    /// Returns the test cases found in the analyzed project as tuples of
+    /// the project file, the tested function and the test case name.
+    pub fn test_cases(&self) -> Vec<(PathBuf, String, String)> {
+        let mut test_cases = Vec::new();
+
+        for project_file in self.analysis.project_files().iter() {
+            for entity in project_file.entities().iter() {
+                for function in entity.described_functions() {
+                    if let Some(description) = &function.description {
+                        for test_case in dsl::test_case_names(description) {
+                            test_cases.push((
+                                project_file.path.clone(),
+                                function.name.clone(),
+                                test_case,
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+
+        test_cases
+    }
+
+    /// Analyzes the target project and validates the project config as
+    /// well as the test descriptions, without synthesizing anything.
+    pub fn check(&mut self, thinline_cfg: &str) -> Fallible<()> {
+        self.analyze(thinline_cfg, false)?;
+
+        let mut problems = Vec::new();
+        for project_file in self.analysis.project_files().iter() {
+            for entity in project_file.entities().iter() {
+                problems.extend(
+                    dsl::validate_entity(&entity)
+                        .iter()
+                        .map(|problem| format!("{}: {}", project_file, problem)),
+                );
+            }
+        }
 
-        for file in self.analysis.project_files().iter() {
-            self.synthesis.process_testfile(file)?;
+        if !problems.is_empty() {
+            return Err(format_err!(
+                "Found {} problem(s) in the test descriptions:\n{}",
+                problems.len(),
+                problems.join("\n")
+            ));
         }
 
         Ok(())
@@ -2,18 +2,17 @@ extern crate clang;
 #[macro_use]
 extern crate clap;
 extern crate env_logger;
+#[macro_use]
 extern crate failure;
 extern crate glob;
 #[macro_use]
 extern crate log;
 extern crate thinlinelib;
 
-use clap::App;
+use clap::{App, ArgMatches};
 use failure::{err_msg, Fallible};
-use std::{env::set_var, path::Path, process::exit};
-use thinlinelib::{
-    language_type::{Cpp, Python, C}, Thinline,
-};
+use std::{env::set_var, fs::File, io::Write, path::Path, process::exit};
+use thinlinelib::{analysis::Strictness, dump::DumpFormat, execution::TestResult, Thinline};
 
 ////////////////////////////////////////////////////////////////////////////////
 
@@ -29,11 +28,14 @@ fn run() -> Fallible<()> {
     let app = App::from_yaml(yaml).version(crate_version!());
     let matches = app.get_matches();
 
+    let (subcommand, sub_matches) = matches.subcommand();
+    let sub_matches = sub_matches.ok_or_else(|| err_msg("CLI subcommand missing."))?;
+
     // Sets up logging depending on verbosity level and quiet flag
-    let quiet = matches.is_present("quiet");
+    let quiet = sub_matches.is_present("quiet");
 
     if !quiet {
-        let lib_log_level = match matches.occurrences_of("verbose") {
+        let lib_log_level = match sub_matches.occurrences_of("verbose") {
             0 => "info",
             1 => "debug",
             _ => "trace",
@@ -45,6 +47,37 @@ fn run() -> Fallible<()> {
         env_logger::init();
     }
 
+    execute(subcommand, sub_matches)
+}
+
+/// Synthesizes the tests of the analyzed entities and, unless it is a dry
+/// run, builds and executes them.
+fn run_tests(thinline: &mut Thinline, dry_run: bool) -> Fallible<Vec<TestResult>> {
+    thinline.synthesize(Path::new("stubs").join("environment"))?;
+
+    if dry_run {
+        return Ok(Vec::new());
+    }
+
+    let executables = thinline.build_tests()?;
+    thinline.execute_tests(&executables)
+}
+
+/// Prints a compact summary of the given test results.
+fn print_summary(results: &[TestResult]) {
+    let failed: Vec<&TestResult> = results.iter().filter(|result| !result.passed).collect();
+
+    println!(
+        "{} passed, {} failed",
+        results.len() - failed.len(),
+        failed.len()
+    );
+    for result in failed {
+        println!("  FAILED {}", result.executable.display());
+    }
+}
+
+fn execute(subcommand: &str, matches: &ArgMatches) -> Fallible<()> {
     // Reads the source directory where file traversing should start.
     let source_directory = matches
         .value_of("SOURCE-DIR")
@@ -55,29 +88,87 @@ fn run() -> Fallible<()> {
         .value_of("project_config")
         .ok_or_else(|| err_msg("CLI parameter 'project_config' missing."))?;
 
-    let language = matches
-        .value_of("language")
-        .ok_or_else(|| err_msg("CLI parameter 'language' missing."))?;
-
     let build = matches.is_present("build");
+    let dry_run = matches.is_present("dry_run");
+
+    // Creates a new Thinline instance, which analyzes the languages of the
+    // project config unless a language is given.
+    let mut thinline = Thinline::new(source_directory);
+    if let Some(language) = matches.value_of("language") {
+        thinline.set_languages(&[language]);
+    }
+    if matches.is_present("no_cache") {
+        thinline.set_cache(false);
+    }
+    if let Some(revision) = matches.value_of("changed_since") {
+        thinline.set_changed_since(revision);
+    }
+    if let Some(jobs) = matches.value_of("jobs") {
+        thinline.set_jobs(
+            jobs.parse()
+                .map_err(|_| format_err!("Invalid number of jobs '{}'.", jobs))?,
+        );
+    }
+    if let Some(strictness) = matches.value_of("strictness") {
+        thinline.set_strictness(Strictness::from_name(strictness)?);
+    }
 
-    // Creates a new Thinline instance
-    match language {
-        "c" => {
-            let mut thinline: Thinline<C> = Thinline::new(source_directory);
+    match subcommand {
+        "analyze" => {
             thinline.analyze(thinline_cfg_name, build)?;
+
+            let entity_tree = match matches.value_of("format") {
+                Some("tree") | None => thinline.entity_tree(),
+                Some(format) => thinline.dump_analysis(&DumpFormat::from_name(format)?)?,
+            };
+
+            if let Some(output) = matches.value_of("output") {
+                File::create(output)?.write_all(entity_tree.as_bytes())?;
+            } else {
+                println!("{}", entity_tree);
+            }
         }
-        "cpp" => {
-            let mut thinline: Thinline<Cpp> = Thinline::new(source_directory);
+        "generate" => {
             thinline.analyze(thinline_cfg_name, build)?;
             thinline.synthesize(Path::new("stubs").join("environment"))?;
         }
-        "python" => {
-            let mut thinline: Thinline<Python> = Thinline::new(source_directory);
+        "run" => {
+            thinline.analyze(thinline_cfg_name, build)?;
+
+            let results = run_tests(&mut thinline, dry_run)?;
+            for result in &results {
+                println!("{}", result);
+            }
+
+            let failed = results.iter().filter(|result| !result.passed).count();
+            if failed > 0 {
+                for result in results.iter().filter(|result| !result.passed) {
+                    println!("{}", result.output);
+                }
+                return Err(format_err!("{} of {} test(s) failed.", failed, results.len()));
+            }
+        }
+        "watch" => {
+            thinline.analyze(thinline_cfg_name, build)?;
+            print_summary(&run_tests(&mut thinline, dry_run)?);
+
+            thinline.watch(|thinline| {
+                print_summary(&run_tests(thinline, dry_run)?);
+                Ok(())
+            })?;
+        }
+        "list" => {
             thinline.analyze(thinline_cfg_name, build)?;
+            for (project_file, function, test_case) in thinline.test_cases() {
+                println!("{}: {}: {}", project_file.display(), function, test_case);
+            }
         }
-        _ => {}
-    };
+        "check" => {
+            thinline.check(thinline_cfg_name)?;
+            info!("Project config and test descriptions are valid.");
+        }
+        _ => return Err(format_err!("Unknown subcommand '{}'.", subcommand)),
+    }
 
     Ok(())
 }
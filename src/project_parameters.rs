@@ -1,8 +1,14 @@
+use directories::BaseDirs;
 use failure::{err_msg, Fallible};
 use run_script::{self, ScriptOptions};
 use std::{env, fs::read_to_string, path::PathBuf};
 use value_parser::{ValueParser, VecStringConversion};
-use yaml_rust::YamlLoader;
+use yaml_rust::{Yaml, YamlLoader};
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// The default directory, relative to the project, the tests are written to.
+static DEFAULT_OUTPUT_DIR: &str = ".thinline";
 
 ////////////////////////////////////////////////////////////////////////////////
 
@@ -77,11 +83,69 @@ impl BuildScript {
 
 ////////////////////////////////////////////////////////////////////////////////
 
+#[derive(Debug, Clone, PartialEq)]
+/// The backend which is used to build the synthesized tests.
+pub enum BuildBackend {
+    /// Generates a CMake project which builds the tests and registers them with CTest.
+    CMake,
+
+    /// Compiles the tests directly with the C/C++ compiler.
+    Compiler,
+}
+
+impl Default for BuildBackend {
+    fn default() -> Self {
+        BuildBackend::CMake
+    }
+}
+
+impl BuildBackend {
+    /// Returns the `BuildBackend` connected to the given name.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use thinlinelib::project_parameters::BuildBackend;
+    ///
+    /// assert_eq!(BuildBackend::from_name("cmake").unwrap(), BuildBackend::CMake);
+    /// assert_eq!(BuildBackend::from_name("compiler").unwrap(), BuildBackend::Compiler);
+    /// assert!(BuildBackend::from_name("make").is_err());
+    /// ```
+    pub fn from_name(name: &str) -> Fallible<Self> {
+        match name {
+            "cmake" => Ok(BuildBackend::CMake),
+            "compiler" => Ok(BuildBackend::Compiler),
+            _ => Err(format_err!("Unknown build backend '{}'.", name)),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Default, Debug)]
+/// The parameters of the built-in compiler driver.
+pub struct CompilerParameters {
+    /// The C compiler, `CC` or `cc` is used when not set.
+    pub cc: Option<String>,
+
+    /// The C++ compiler, `CXX` or `c++` is used when not set.
+    pub cxx: Option<String>,
+
+    /// Additional flags which are passed to the compiler.
+    pub flags: Vec<String>,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
 #[derive(Default, Debug)]
 /// The parsed project parameters.
 pub struct ProjectParameters {
-    /// The language of the source project (e.g. c, cpp or python)
-    pub language: String,
+    /// The languages of the source project (e.g. c, cpp or python)
+    pub languages: Vec<String>,
+
+    /// The file extensions of the languages which override the default
+    /// ones, e.g. to analyze `.h` headers as C++.
+    pub file_types: Vec<(String, Vec<String>)>,
 
     /// Test environment which should be used (e.g. google test)
     pub test_env: String,
@@ -89,6 +153,12 @@ pub struct ProjectParameters {
     /// The build steps which should be executed when the build-option is set.
     pub build_script: BuildScript,
 
+    /// The backend which builds the synthesized tests.
+    pub build_backend: BuildBackend,
+
+    /// The parameters of the built-in compiler driver.
+    pub compiler: CompilerParameters,
+
     /// Paths to libraries which should be linked.
     pub lib_paths: Vec<String>,
 
@@ -97,12 +167,85 @@ pub struct ProjectParameters {
 
     /// The include directories necessary to build the tests.
     pub include_dirs: Vec<String>,
+
+    /// Additional flags which are passed to the parsers of the analysis,
+    /// e.g. defines or the language standard.
+    pub analysis_flags: Vec<String>,
+
+    /// The directory where the synthesized test project is written to.
+    pub output_dir: String,
+
+    /// The directory, relative to the project, Python modules are imported
+    /// relative to. By default it is derived from the packages of every file.
+    pub module_root: Option<String>,
+
+    /// Content which is placed before the includes of the generated test header.
+    pub tlg_prefix: Option<String>,
+
+    /// Content which is placed after the includes of the generated test header.
+    pub tlg_suffix: Option<String>,
 }
 
 impl ProjectParameters {
     /// Creates a new `ProjectParameters` instance.
     pub fn new() -> Self {
-        Self::default()
+        Self {
+            output_dir: String::from(DEFAULT_OUTPUT_DIR),
+            ..Self::default()
+        }
+    }
+
+    /// Traverses upwared through the `seed_dir`s parent directories until
+    /// `thinline.yml` configuration exists in dir or home directory is reached.
+    fn find_config(seed_dir: &PathBuf, config_name: &str) -> Option<PathBuf> {
+        if let Some(base_dirs) = BaseDirs::new() {
+            let home_dir = base_dirs.home_dir();
+            let mut project_config = seed_dir.clone();
+
+            loop {
+                if project_config == home_dir || project_config.join(config_name).is_file() {
+                    trace!("Config dir: {:?}", project_config);
+                    break;
+                }
+
+                if let Some(parent) = project_config.clone().as_path().parent() {
+                    project_config = parent.to_path_buf();
+                } else {
+                    return None;
+                }
+            }
+
+            return Some(project_config.join(config_name));
+        }
+
+        None
+    }
+
+    /// Searches the given config yaml starting at the project directory
+    /// and parses the project parameters from it.
+    pub fn from_project_dir(project_dir: &PathBuf, config_name: &str) -> Fallible<Self> {
+        if let Some(project_config) = Self::find_config(project_dir, config_name) {
+            if !project_config.exists() || !project_config.is_file() {
+                return Err(format_err!(
+                    "The given project config file '{}' does not exist or is a directory.",
+                    project_config
+                        .to_str()
+                        .ok_or_else(|| err_msg("Unable to stringify project config file."))?
+                ));
+            }
+
+            return Self::parse(
+                project_config
+                    .to_str()
+                    .ok_or_else(|| err_msg("Unable to stringify project config file."))?,
+            );
+        }
+
+        Err(format_err!(
+            "Unable to find the project config '{}' for '{}'.",
+            config_name,
+            project_dir.display()
+        ))
     }
 
     /// Parses the project parameters from the given yaml file.
@@ -111,10 +254,34 @@ impl ProjectParameters {
             if let Some(yml_param) = yml_params.get(0) {
                 let mut params = Self::new();
 
-                params.language =
-                    String::from(yml_param.get_str(&["language"]).ok_or_else(|| {
-                        err_msg("Unable to get parameters for mandatory 'language'.")
-                    })?);
+                // The language is either given as single value or as list for mixed projects.
+                params.languages = yml_param.get_str_vec(&["language"]).to_string_vec();
+                if params.languages.is_empty() {
+                    params.languages.push(String::from(
+                        yml_param.get_str(&["language"]).ok_or_else(|| {
+                            err_msg("Unable to get parameters for mandatory 'language'.")
+                        })?,
+                    ));
+                }
+                // The file types of a language are either a single extension or a list.
+                if let Some(file_types) = yml_param["file_types"].as_hash() {
+                    for language in file_types.keys().filter_map(Yaml::as_str) {
+                        let mut extensions = yml_param
+                            .get_str_vec(&["file_types", language])
+                            .to_string_vec();
+                        if extensions.is_empty() {
+                            extensions.extend(
+                                yml_param
+                                    .get_str(&["file_types", language])
+                                    .map(String::from),
+                            );
+                        }
+                        params
+                            .file_types
+                            .push((String::from(language), extensions));
+                    }
+                }
+
                 params.test_env =
                     String::from(yml_param.get_str(&["test_env"]).ok_or_else(|| {
                         err_msg("Unable to get parameters for mandatory 'test_env'.")
@@ -122,6 +289,8 @@ impl ProjectParameters {
 
                 params.source_dirs = yml_param.get_str_vec(&["analysis_dirs"]).to_string_vec();
                 params.include_dirs = yml_param.get_str_vec(&["include_dirs"]).to_string_vec();
+                params.analysis_flags =
+                    yml_param.get_str_vec(&["analysis_flags"]).to_string_vec();
 
                 params.build_script.log = yml_param.get_bool(&["build_script", "log"], true);
                 params.build_script.linux = yml_param
@@ -133,6 +302,25 @@ impl ProjectParameters {
 
                 params.lib_paths = yml_param.get_str_vec(&["libs"]).to_string_vec();
 
+                if let Some(build_backend) = yml_param.get_str(&["build_backend"]) {
+                    params.build_backend = BuildBackend::from_name(build_backend)?;
+                }
+
+                params.compiler.cc = yml_param.get_str(&["compiler", "cc"]).map(String::from);
+                params.compiler.cxx = yml_param.get_str(&["compiler", "cxx"]).map(String::from);
+                params.compiler.flags = yml_param
+                    .get_str_vec(&["compiler", "flags"])
+                    .to_string_vec();
+
+                if let Some(output_dir) = yml_param.get_str(&["output_dir"]) {
+                    params.output_dir = String::from(output_dir);
+                }
+
+                params.module_root = yml_param.get_str(&["module_root"]).map(String::from);
+
+                params.tlg_prefix = yml_param.get_str(&["tlg", "prefix"]).map(String::from);
+                params.tlg_suffix = yml_param.get_str(&["tlg", "suffix"]).map(String::from);
+
                 return Ok(params);
             }
         }
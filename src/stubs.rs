@@ -1,7 +1,7 @@
 use failure::Fallible;
 use std::{collections::HashMap, fs::read_to_string, path::PathBuf};
-use value_parser::ValueParser;
-use yaml_rust::YamlLoader;
+use value_parser::{ValueParser, VecStringConversion};
+use yaml_rust::{Yaml, YamlLoader};
 
 ////////////////////////////////////////////////////////////////////////////////
 
@@ -25,14 +25,54 @@ impl Stub {
 
     /// Formats a `Stub` with the given `HashMap`.
     /// The keys within the stub context are replaced with the
-    /// connected values.
+    /// connected values. The continuation lines of multi-line values are
+    /// indented like the line of the key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use thinlinelib::stubs::Stub;
+    ///
+    /// let stub = Stub::from_str("def test():\n    #TEST_CONTEXT");
+    /// let dict: HashMap<&str, &str> = [("#TEST_CONTEXT", "a = 1\nassert a == 1")]
+    ///     .iter()
+    ///     .cloned()
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     stub.format(&dict).unwrap().unwrap(),
+    ///     "def test():\n    a = 1\n    assert a == 1"
+    /// );
+    /// ```
     pub fn format(&self, dict: &HashMap<&str, &str>) -> Fallible<Option<String>> {
         let mut form_stub: String = self.content.clone();
         for (key, val) in dict.iter() {
-            form_stub = form_stub.replace(format!("{}", key).as_str(), val);
+            form_stub = Self::replace_indented(&form_stub, key, val);
         }
         Ok(Some(form_stub))
     }
+
+    /// Replaces the key within the content with the value, whose
+    /// continuation lines are indented like the line of the key.
+    fn replace_indented(content: &str, key: &str, value: &str) -> String {
+        let mut replaced = String::with_capacity(content.len());
+        let mut last = 0;
+        for (offset, _) in content.match_indices(key) {
+            let line_start = content[..offset].rfind('\n').map_or(0, |newline| newline + 1);
+            let indentation: String = content[line_start..]
+                .chars()
+                .take_while(|c| *c == ' ' || *c == '\t')
+                .collect();
+
+            replaced.push_str(&content[last..offset]);
+            replaced.push_str(&value.replace('\n', &format!("\n{}", indentation)));
+            last = offset + key.len();
+        }
+        replaced.push_str(&content[last..]);
+
+        replaced
+    }
 }
 
 trait StubConversion {
@@ -58,8 +98,18 @@ pub struct Stubs {
     pub file: Option<Stub>,
     pub class: Option<Stub>,
     pub function: Option<Stub>,
+    pub async_function: Option<Stub>,
+    pub typed_class: Option<Stub>,
+    pub typed_function: Option<Stub>,
+    pub import: Option<Stub>,
+    pub interpreted: bool,
+    pub generate_only: bool,
     pub output_format: Option<String>,
-    pub test_functions: Vec<Stub>,
+    /// The stubs of the assertions by their name, e.g. `TL_EQ`.
+    pub test_functions: HashMap<String, Stub>,
+    pub cmake_package: Option<String>,
+    pub cmake_libraries: Vec<String>,
+    pub compiler_libraries: Vec<String>,
 }
 
 impl Stubs {
@@ -69,8 +119,17 @@ impl Stubs {
             file: None,
             class: None,
             function: None,
+            async_function: None,
+            typed_class: None,
+            typed_function: None,
+            import: None,
+            interpreted: false,
+            generate_only: false,
             output_format: None,
-            test_functions: Vec::new(),
+            test_functions: HashMap::new(),
+            cmake_package: None,
+            cmake_libraries: Vec::new(),
+            compiler_libraries: Vec::new(),
         }
     }
 
@@ -88,11 +147,47 @@ impl Stubs {
             self.function = yml_param
                 .get_str_or_file_content(&[test_env, "function"], base_path, STUB_EXTENSION)
                 .to_stub();
+            self.async_function = yml_param
+                .get_str_or_file_content(&[test_env, "async_function"], base_path, STUB_EXTENSION)
+                .to_stub();
+            self.typed_class = yml_param
+                .get_str_or_file_content(&[test_env, "typed_class"], base_path, STUB_EXTENSION)
+                .to_stub();
+            self.typed_function = yml_param
+                .get_str_or_file_content(&[test_env, "typed_function"], base_path, STUB_EXTENSION)
+                .to_stub();
+
+            self.import = yml_param
+                .get_str_or_file_content(&[test_env, "import"], base_path, STUB_EXTENSION)
+                .to_stub();
+            self.interpreted = yml_param.get_bool(&[test_env, "interpreted"], false);
+            self.generate_only = yml_param.get_bool(&[test_env, "generate_only"], false);
 
             if let Some(output_format) = yml_param.get_str(&[test_env, "output_format"]) {
                 self.output_format = Some(String::from(output_format));
             }
 
+            if let Some(test_functions) = yml_param[test_env]["test_functions"].as_hash() {
+                for name in test_functions.keys().filter_map(Yaml::as_str) {
+                    if let Some(stub) = yml_param
+                        .get_str(&[test_env, "test_functions", name, "stub"])
+                        .to_stub()
+                    {
+                        self.test_functions.insert(String::from(name), stub);
+                    }
+                }
+            }
+
+            self.cmake_package = yml_param
+                .get_str(&[test_env, "cmake", "package"])
+                .map(String::from);
+            self.cmake_libraries = yml_param
+                .get_str_vec(&[test_env, "cmake", "libraries"])
+                .to_string_vec();
+            self.compiler_libraries = yml_param
+                .get_str_vec(&[test_env, "compiler", "libraries"])
+                .to_string_vec();
+
             debug!("Parsed stubs: {:#?}", self);
         }
 
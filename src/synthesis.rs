@@ -1,21 +1,46 @@
-use analysis::ProjectFile;
-use entity::{Entity, EntityType};
-use failure::Fallible;
-use language_type::LanguageType;
-use std::{collections::HashMap, marker::PhantomData, path::PathBuf};
-use stubs::Stubs;
+use analysis::{Description, Function, ProjectFile};
+use cache::Fnv1a;
+use entity::{Access, Entity, EntityType};
+use dsl;
+use failure::{err_msg, Fallible};
+use std::{
+    collections::HashMap, ffi::OsStr, fs::{create_dir_all, read_to_string, File}, io::Write,
+    path::{Path, PathBuf},
+};
+use stubs::{Stub, Stubs};
 
 ////////////////////////////////////////////////////////////////////////////////
 
-static STUB_ID_SET_UP_CONTEXT: &str = "#SET_UP";
-static STUB_ID_TEAR_DOWN_CONTEXT: &str = "#TEAR_DOWN";
-static STUB_ID_CONSTRUCTOR_CONTEXT: &str = "#CONSTRUCTOR";
-static STUB_ID_DESTRUCTOR_CONTEXT: &str = "#DESTRUCTOR";
+static STUB_ID_SET_UP_CONTEXT: &str = "#SET_UP_CONTEXT";
+static STUB_ID_TEAR_DOWN_CONTEXT: &str = "#TEAR_DOWN_CONTEXT";
+static STUB_ID_CONSTRUCTOR_CONTEXT: &str = "#CONSTRUCTOR_CONTEXT";
+static STUB_ID_DESTRUCTOR_CONTEXT: &str = "#DESTRUCTOR_CONTEXT";
 static STUB_ID_CLASS_CONTEXT: &str = "#CLASS_CONTEXT";
 
 static STUB_ID_TEST_CLASS: &str = "#TEST_CLASS";
 static STUB_ID_TEST_NAME: &str = "#TEST_NAME";
 static STUB_ID_TEST_CONTEXT: &str = "#TEST_CONTEXT";
+static STUB_ID_TEST_TYPES: &str = "#TEST_TYPES";
+
+static STUB_ID_ASSERTION_CALL: &str = "//#ARG_0#/";
+static STUB_ID_ASSERTION_EXPECTED: &str = "//#ARG_1#/";
+
+static STUB_ID_MODULE_ROOT: &str = "#MODULE_ROOT";
+static STUB_ID_MODULE_PATH: &str = "#MODULE_PATH";
+static STUB_ID_MODULE_NAMES: &str = "#MODULE_NAMES";
+
+static STUB_ID_TEST_IMPORTS: &str = "#TEST_IMPORTS";
+static STUB_ID_TEST_NAMESPACES: &str = "#TEST_NAMESPACES";
+static STUB_ID_PRECLASS_CONTEXT: &str = "#PRECLASS_CONTEXT";
+static STUB_ID_TEST_CLASSES: &str = "#TEST_CLASSES";
+static STUB_ID_TEST_CASES: &str = "#TEST_CASES";
+
+static TLG_ID_PREFIX: &str = "// USER_SPECIFIC_PREFIX_CONTENT //";
+static TLG_ID_INCLUDES: &str = "// INCLUDES //";
+static TLG_ID_SUFFIX: &str = "// USER_SPECIFIC_SUFFIX_CONTENT //";
+
+/// The name of the generated header every test file includes.
+pub static TLG_HEADER_NAME: &str = "__tlg__.h";
 
 type StubContext = String;
 
@@ -111,18 +136,38 @@ impl TestClass {
 
 ////////////////////////////////////////////////////////////////////////////////
 
+/// A synthesized test case together with the function it originates from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestCase {
+    pub function: String,
+    pub content: String,
+}
+
+impl TestCase {
+    /// Creates a new `TestCase` instance.
+    pub fn new<S: Into<String>>(function: S, content: S) -> Self {
+        Self {
+            function: function.into(),
+            content: content.into(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
 #[derive(Default, Debug)]
-pub struct TestFile<T> {
+pub struct TestFile {
     pub path: PathBuf,
+    pub source: PathBuf,
     pub entities: Vec<Entity>,
-    pub pf_type: PhantomData<T>,
+    pub imports: Vec<String>,
+    pub preclass_context: String,
+    pub test_classes: Vec<String>,
+    pub test_cases: Vec<TestCase>,
 }
 
 /// Represents a test file.
-impl<T> TestFile<T>
-where
-    T: LanguageType,
-{
+impl TestFile {
     /// Creates a new `TestFile` instance.
     ///
     /// # Example
@@ -130,45 +175,102 @@ where
     /// ```
     /// use std::path::PathBuf;
     /// use thinlinelib::synthesis::TestFile;
-    /// use thinlinelib::language_type::C;
     ///
-    /// let test_file: TestFile<C> = TestFile::new("test_file");
+    /// let test_file = TestFile::new("test_file");
     ///
     /// assert!(test_file.entities.is_empty());
+    /// assert!(test_file.imports.is_empty());
+    /// assert!(test_file.test_cases.is_empty());
     /// ```
     pub fn new<S: Into<PathBuf>>(path: S) -> Self {
         Self {
             path: path.into(),
+            source: PathBuf::new(),
             entities: Vec::new(),
-            pf_type: PhantomData,
+            imports: Vec::new(),
+            preclass_context: String::new(),
+            test_classes: Vec::new(),
+            test_cases: Vec::new(),
+        }
+    }
+
+    /// Formats the given file stub with the test classes and cases
+    /// of the `TestFile`.
+    pub fn render(&self, file_stub: &Stub) -> Fallible<Option<String>> {
+        let imports = self.imports.join("\n");
+        let test_classes = self.test_classes.join("\n");
+        let test_cases = self
+            .test_cases
+            .iter()
+            .map(|test_case| test_case.content.as_str())
+            .collect::<Vec<&str>>()
+            .join("\n");
+        let file_stub_format_hashes: HashMap<&str, &str> = [
+            (STUB_ID_TEST_IMPORTS, imports.as_str()),
+            (STUB_ID_TEST_NAMESPACES, ""),
+            (STUB_ID_PRECLASS_CONTEXT, self.preclass_context.as_str()),
+            (STUB_ID_TEST_CLASSES, test_classes.as_str()),
+            (STUB_ID_TEST_CASES, test_cases.as_str()),
+        ].iter()
+            .cloned()
+            .collect();
+
+        file_stub.format(&file_stub_format_hashes)
+    }
+
+    /// Returns the `TestCase` the given line (starting at 1) of the rendered
+    /// test file belongs to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use thinlinelib::synthesis::{TestCase, TestFile};
+    ///
+    /// let mut test_file = TestFile::new("test_file");
+    /// test_file.test_cases.push(TestCase::new("fct", "TEST(a, b) {\n}"));
+    ///
+    /// let rendered = "#include <gtest/gtest.h>\nTEST(a, b) {\n}\n";
+    ///
+    /// assert!(test_file.test_case_at_line(rendered, 1).is_none());
+    /// assert_eq!(test_file.test_case_at_line(rendered, 3).unwrap().function, "fct");
+    /// ```
+    pub fn test_case_at_line(&self, rendered: &str, line: usize) -> Option<&TestCase> {
+        for test_case in &self.test_cases {
+            if let Some(offset) = rendered.find(test_case.content.as_str()) {
+                let first_line = rendered[..offset].matches('\n').count() + 1;
+                let last_line = first_line + test_case.content.matches('\n').count();
+                if line >= first_line && line <= last_line {
+                    return Some(test_case);
+                }
+            }
         }
+
+        None
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
 
 #[derive(Default, Debug)]
-pub struct Synthesis<T>
-where
-    T: LanguageType,
-{
+pub struct Synthesis {
     stubs: Stubs,
-    pub test_files: Vec<TestFile<T>>,
+    pub test_files: Vec<TestFile>,
+
+    /// The directory the test files are named relative to, which keeps the
+    /// tests of equally named sources apart. Without it, the test files are
+    /// named by the file names of the sources.
+    pub project_dir: Option<PathBuf>,
 }
 
-impl<T> Synthesis<T>
-where
-    T: LanguageType,
-{
+impl Synthesis {
     /// Creates a new `Synthesis` instance.
     ///
     /// # Example
     ///
     /// ```
     /// use thinlinelib::synthesis::Synthesis;
-    /// use thinlinelib::language_type::C;
     ///
-    /// let synthesis: Synthesis<C> = Synthesis::new();
+    /// let synthesis = Synthesis::new();
     ///
     /// assert_eq!(synthesis.test_files.len(), 0);
     /// ```
@@ -176,6 +278,7 @@ where
         Self {
             stubs: Stubs::new(),
             test_files: Vec::new(),
+            project_dir: None,
         }
     }
 
@@ -184,31 +287,245 @@ where
         self.stubs.parse(yml, test_env, base_path)
     }
 
-    fn process_entities(&self, parent: &Entity, children: &[EntityType]) -> Fallible<()> {
+    /// Returns the name of the test class of the entity with the given
+    /// qualified name, which distinguishes equally named entities of
+    /// different scopes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use thinlinelib::synthesis::Synthesis;
+    ///
+    /// assert_eq!(Synthesis::test_class_name("ns1::c1"), "ns1_c1");
+    /// assert_eq!(Synthesis::test_class_name("ns1::c1<int>"), "ns1_c1_int_");
+    /// assert_ne!(Synthesis::test_class_name("ns2::c1"), Synthesis::test_class_name("ns1::c1"));
+    /// ```
+    pub fn test_class_name(qualified_name: &str) -> String {
+        qualified_name
+            .replace("::", "_")
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect()
+    }
+
+    /// Returns the name of the tests of the given function, which replaces
+    /// the characters of names like Objective-C selectors (`add:to:`) or C++
+    /// operators which are not valid within identifiers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use thinlinelib::analysis::Function;
+    /// use thinlinelib::synthesis::Synthesis;
+    ///
+    /// assert_eq!(Synthesis::test_name(&Function::new("add")), "add");
+    /// assert_eq!(Synthesis::test_name(&Function::new("add:to:")), "add_to_");
+    /// ```
+    pub fn test_name(function: &Function) -> String {
+        Self::test_class_name(&function.name)
+    }
+
+    /// Returns the name of the tests of an overloaded function, which is
+    /// suffixed by a short hash of its signature to be unique among the
+    /// overloads.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use thinlinelib::analysis::{Argument, Function};
+    /// use thinlinelib::synthesis::Synthesis;
+    ///
+    /// let mut add_int = Function::new("add");
+    /// add_int.set_arguments(&vec![Argument::new("a", Some("int"))]);
+    /// let mut add_double = Function::new("add");
+    /// add_double.set_arguments(&vec![Argument::new("a", Some("double"))]);
+    ///
+    /// assert!(Synthesis::overload_test_name(&add_int).starts_with("add_"));
+    /// assert_ne!(
+    ///     Synthesis::overload_test_name(&add_int),
+    ///     Synthesis::overload_test_name(&add_double)
+    /// );
+    /// ```
+    pub fn overload_test_name(function: &Function) -> String {
+        let mut hasher = Fnv1a::new();
+        hasher.write(function.signature().as_bytes());
+
+        format!("{}_{}", Self::test_name(function), &hasher.finish()[..8])
+    }
+
+    /// Returns the test context of a function description. Its plain code
+    /// lines are taken over, while its assertions are written by the
+    /// assertion stubs of the test environment.
+    fn test_context(&self, function: &Function, description: &Description) -> Fallible<String> {
+        let mut lines = Vec::new();
+
+        for line in &description.lines {
+            match dsl::Statement::parse(line) {
+                Ok(dsl::Statement::Code(code)) => lines.push(code),
+                Ok(dsl::Statement::Assertion(assertion)) => {
+                    let stub_name = format!("TL_{}", assertion.comparison);
+                    let assertion_stub =
+                        self.stubs().test_functions.get(&stub_name).ok_or_else(|| {
+                            format_err!(
+                                "The test environment has no '{}' for '{}'.",
+                                stub_name,
+                                function.qualified_name
+                            )
+                        })?;
+                    let call = assertion.call.render(function).map_err(|err| {
+                        format_err!("{} ('{}' of '{}')", err, line, function.qualified_name)
+                    })?;
+                    let assertion_stub_format_hashes: HashMap<&str, &str> = [
+                        (STUB_ID_ASSERTION_CALL, call.as_str()),
+                        (STUB_ID_ASSERTION_EXPECTED, assertion.expected.as_str()),
+                    ].iter()
+                        .cloned()
+                        .collect();
+                    lines.extend(assertion_stub.format(&assertion_stub_format_hashes)?);
+                }
+                _ => {}
+            }
+        }
+
+        Ok(lines.join("\n"))
+    }
+
+    /// Returns the code of the contexts of a test class description by the
+    /// stub id they are placed at. Every line starts on its own, indented
+    /// line, since the stubs place the contexts right after an opening brace.
+    fn class_contexts(description: Option<&Description>) -> HashMap<&'static str, String> {
+        let mut class_contexts: HashMap<&'static str, String> = [
+            STUB_ID_CONSTRUCTOR_CONTEXT,
+            STUB_ID_DESTRUCTOR_CONTEXT,
+            STUB_ID_SET_UP_CONTEXT,
+            STUB_ID_TEAR_DOWN_CONTEXT,
+            STUB_ID_CLASS_CONTEXT,
+        ].iter()
+            .map(|stub_id| (*stub_id, String::new()))
+            .collect();
+
+        if let Some(description) = description {
+            for (context, lines) in dsl::test_contexts(description) {
+                let stub_id = match context.as_str() {
+                    "CONSTRUCTOR" => STUB_ID_CONSTRUCTOR_CONTEXT,
+                    "DESTRUCTOR" => STUB_ID_DESTRUCTOR_CONTEXT,
+                    "SET_UP" => STUB_ID_SET_UP_CONTEXT,
+                    "TEAR_DOWN" => STUB_ID_TEAR_DOWN_CONTEXT,
+                    _ => STUB_ID_CLASS_CONTEXT,
+                };
+                let code: String = lines.iter().map(|line| format!("\n    {}", line)).collect();
+                class_contexts.insert(stub_id, code);
+            }
+        }
+
+        class_contexts
+    }
+
+    /// Returns the stub of the test cases of the given function. Async
+    /// functions have to be awaited by their tests, so they use the async
+    /// stub of the test environment if available.
+    fn function_stub(&self, function: &Function) -> Option<&Stub> {
+        if function.is_async {
+            if let Some(async_function_stub) = &self.stubs().async_function {
+                return Some(async_function_stub);
+            }
+            warn!(
+                "The test environment has no async tests for '{}'.",
+                function.qualified_name
+            );
+        }
+
+        self.stubs().function.as_ref()
+    }
+
+    fn process_entities(
+        &self,
+        class_name: &str,
+        class_description: Option<&Description>,
+        children: &[EntityType],
+        test_file: &mut TestFile,
+    ) -> Fallible<()> {
+        let mut has_test_cases = false;
+        let class_contexts = Self::class_contexts(class_description);
+        let mut class_stub_format_hashes: HashMap<&str, &str> = class_contexts
+            .iter()
+            .map(|(stub_id, code)| (*stub_id, code.as_str()))
+            .collect();
+        class_stub_format_hashes.insert(STUB_ID_TEST_CLASS, class_name);
+
+        // Overloaded functions share their name, so their tests are
+        // distinguished by the signature.
+        let mut name_counts: HashMap<&str, usize> = HashMap::new();
+        for child in children {
+            if let EntityType::Function(function) = child {
+                *name_counts.entry(function.name.as_str()).or_insert(0) += 1;
+            }
+        }
+
         for child in children {
             match child {
                 EntityType::Function(function) => {
                     if let Some(description) = &function.description {
+                        if let Some(reason) = function.untestable_reason() {
+                            warn!(
+                                "Skipping the tests of '{}', since {}.",
+                                function.qualified_name, reason
+                            );
+                            continue;
+                        }
+                        if function.access.is_some() && function.access != Some(Access::Public) {
+                            warn!(
+                                "The tests of '{}' will not compile unless it is made accessible.",
+                                function.qualified_name
+                            );
+                        }
+
+                        let test_name = if name_counts[function.name.as_str()] > 1 {
+                            Self::overload_test_name(function)
+                        } else {
+                            Self::test_name(function)
+                        };
+
+                        let types = dsl::test_types(description);
+                        if !types.is_empty() {
+                            self.process_typed_function(
+                                &class_stub_format_hashes,
+                                function,
+                                &test_name,
+                                description,
+                                &types,
+                                test_file,
+                            )?;
+                            continue;
+                        }
+
                         trace!(
                             "Description found for function {} with parent {}: {:?}",
                             function.name,
-                            parent.name,
+                            class_name,
                             description.lines
                         );
-                        if let Some(function_stub) = &self.stubs().function {
+                        if let Some(function_stub) = self.function_stub(function) {
+                            let test_context = self.test_context(function, description)?;
                             let function_stub_format_hashes: HashMap<
                                 &str,
                                 &str,
                             > = [
-                                (STUB_ID_TEST_CLASS, parent.name.as_str()),
-                                (STUB_ID_TEST_NAME, function.name.as_str()),
+                                (STUB_ID_TEST_CLASS, class_name),
+                                (STUB_ID_TEST_NAME, test_name.as_str()),
+                                (STUB_ID_TEST_CONTEXT, test_context.as_str()),
                             ].iter()
                                 .cloned()
                                 .collect();
-                            debug!(
-                                "formatted stub: {:?}",
+                            if let Some(test_case) =
                                 function_stub.format(&function_stub_format_hashes)?
-                            );
+                            {
+                                debug!("formatted stub: {:?}", test_case);
+                                test_file
+                                    .test_cases
+                                    .push(TestCase::new(function.name.clone(), test_case));
+                                has_test_cases = true;
+                            }
                         }
                     }
                 }
@@ -217,27 +534,250 @@ where
                         trace!(
                             "Description found for entity {} with parent {}: {:?}",
                             entity.name,
-                            parent.name,
+                            class_name,
                             description.lines
                         );
                     }
-                    self.process_entities(entity, &entity.entities)?;
+                    self.process_entities(
+                        &Self::test_class_name(&entity.qualified_name),
+                        entity.description.as_ref(),
+                        &entity.entities,
+                        test_file,
+                    )?;
                 }
                 _ => {}
             }
         }
 
+        // Every test case needs a fixture class to be executed with.
+        if has_test_cases {
+            if let Some(class_stub) = &self.stubs().class {
+                if let Some(test_class) = class_stub.format(&class_stub_format_hashes)? {
+                    test_file.test_classes.push(test_class);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Adds the typed test of a function template, which is executed for
+    /// each of the given types within its own typed fixture class. The
+    /// typed class takes over the contexts of the given class stub values.
+    fn process_typed_function(
+        &self,
+        class_stub_format_hashes: &HashMap<&str, &str>,
+        function: &Function,
+        test_name: &str,
+        description: &Description,
+        types: &[String],
+        test_file: &mut TestFile,
+    ) -> Fallible<()> {
+        let (typed_class_stub, typed_function_stub) =
+            match (&self.stubs().typed_class, &self.stubs().typed_function) {
+                (Some(typed_class_stub), Some(typed_function_stub)) => {
+                    (typed_class_stub, typed_function_stub)
+                }
+                _ => {
+                    warn!(
+                        "The test environment has no typed tests for '{}'.",
+                        function.name
+                    );
+                    return Ok(());
+                }
+            };
+
+        let class_name = class_stub_format_hashes[STUB_ID_TEST_CLASS];
+        let typed_class_name = Self::test_class_name(&format!("{}_{}", class_name, test_name));
+        let types = types.join(", ");
+        let mut class_stub_format_hashes = class_stub_format_hashes.clone();
+        class_stub_format_hashes.insert(STUB_ID_TEST_CLASS, typed_class_name.as_str());
+        class_stub_format_hashes.insert(STUB_ID_TEST_TYPES, types.as_str());
+        if let Some(test_class) = typed_class_stub.format(&class_stub_format_hashes)? {
+            test_file.test_classes.push(test_class);
+        }
+
+        let test_context = self.test_context(function, description)?;
+        let function_stub_format_hashes: HashMap<&str, &str> = [
+            (STUB_ID_TEST_CLASS, typed_class_name.as_str()),
+            (STUB_ID_TEST_NAME, test_name),
+            (STUB_ID_TEST_CONTEXT, test_context.as_str()),
+        ].iter()
+            .cloned()
+            .collect();
+        if let Some(test_case) = typed_function_stub.format(&function_stub_format_hashes)? {
+            debug!("formatted stub: {:?}", test_case);
+            test_file
+                .test_cases
+                .push(TestCase::new(function.name.clone(), test_case));
+        }
+
         Ok(())
     }
 
-    pub fn process_testfile(&self, project_file: &ProjectFile<T>) -> Fallible<()> {
+    /// Returns the import of the module of the given project file, which
+    /// imports the module itself as well as its top level functions and
+    /// classes. It is `None` for files without module path or test
+    /// environments without import stub.
+    fn import(&self, project_file: &ProjectFile) -> Fallible<Option<String>> {
+        let (import_stub, module_path) = match (&self.stubs().import, &project_file.module_path) {
+            (Some(import_stub), Some(module_path)) => (import_stub, module_path),
+            _ => return Ok(None),
+        };
+
+        let entities = project_file.entities();
+        let mut names: Vec<&str> = Vec::new();
+        for entity in entities.iter() {
+            for child in &entity.entities {
+                let name = match child {
+                    EntityType::Function(function) => function.name.as_str(),
+                    EntityType::Entity(entity) => entity.name.as_str(),
+                    _ => continue,
+                };
+                if !names.contains(&name) {
+                    names.push(name);
+                }
+            }
+        }
+        let names = if names.is_empty() {
+            String::from("*")
+        } else {
+            names.join(", ")
+        };
+
+        let module_root = module_path
+            .root
+            .canonicalize()
+            .unwrap_or_else(|_| module_path.root.clone());
+        let module_root = module_root
+            .to_str()
+            .ok_or_else(|| err_msg("Unable to stringify the module root."))?;
+        let import_stub_format_hashes: HashMap<&str, &str> = [
+            (STUB_ID_MODULE_ROOT, module_root),
+            (STUB_ID_MODULE_PATH, module_path.name.as_str()),
+            (STUB_ID_MODULE_NAMES, names.as_str()),
+        ].iter()
+            .cloned()
+            .collect();
+
+        import_stub.format(&import_stub_format_hashes)
+    }
+
+    /// Returns the name of the test file of the given source, without prefix
+    /// and extension, which is built from its path relative to the project
+    /// directory, e.g. `src_util_c` for `src/util.c`.
+    fn test_file_name(&self, path: &Path) -> Option<String> {
+        let relative_path = path.strip_prefix(self.project_dir.as_ref()?).ok()?;
+
+        Some(
+            relative_path
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy().replace('.', "_"))
+                .collect::<Vec<String>>()
+                .join("_"),
+        )
+    }
+
+    /// Processes the entities of a `ProjectFile` and adds a `TestFile`
+    /// containing the synthesized test cases.
+    pub fn process_testfile(&mut self, project_file: &ProjectFile) -> Fallible<()> {
+        let file_name = project_file
+            .path
+            .file_name()
+            .and_then(OsStr::to_str)
+            .ok_or_else(|| err_msg("Unable to stringify project file name."))?;
+
+        // Test cases of the file scope are grouped by a fixture named like the file.
+        let file_class_name = file_name.replace('.', "_");
+        let mut test_file = TestFile::new(format!(
+            "test_{}.{}",
+            self.test_file_name(&project_file.path)
+                .unwrap_or_else(|| file_class_name.clone()),
+            self.stubs()
+                .output_format
+                .as_ref()
+                .map(String::as_str)
+                .unwrap_or("")
+        ));
+        test_file.source = project_file.path.clone();
+        if let Some(import) = self.import(project_file)? {
+            test_file.imports.push(import);
+        }
+
         for entity in project_file.entities().iter() {
-            self.process_entities(&entity, &entity.entities)?;
+            let class_name = if entity.qualified_name.is_empty() {
+                file_class_name.clone()
+            } else {
+                Self::test_class_name(&entity.qualified_name)
+            };
+
+            // The set up and class contexts of a file description, like a
+            // Python module docstring, are placed before the test classes
+            // and thus apply to every test of the file.
+            if let Some(description) = &entity.description {
+                let contexts = dsl::test_contexts(description);
+                for lines in ["SET_UP", "CLASS"].iter().filter_map(|name| contexts.get(*name)) {
+                    for line in lines {
+                        test_file.preclass_context.push_str(line);
+                        test_file.preclass_context.push('\n');
+                    }
+                }
+            }
+
+            self.process_entities(&class_name, None, &entity.entities, &mut test_file)?;
+        }
+
+        if self.stubs().output_format.is_some() && !test_file.test_cases.is_empty() {
+            self.test_files.push(test_file);
+        }
+
+        Ok(())
+    }
+
+    /// Writes the synthesized test files to the given output directory.
+    pub fn write_test_files(&self, output_dir: &Path) -> Fallible<()> {
+        if let Some(file_stub) = &self.stubs().file {
+            create_dir_all(output_dir)?;
+
+            for test_file in &self.test_files {
+                if let Some(content) = test_file.render(file_stub)? {
+                    let path = output_dir.join(&test_file.path);
+                    info!("Writing test file '{}'", path.display());
+                    File::create(path)?.write_all(content.as_bytes())?;
+                }
+            }
         }
 
         Ok(())
     }
 
+    /// Writes the header which is included by every test file. It includes
+    /// the given headers, surrounded by the given prefix and suffix.
+    pub fn write_tlg_header(
+        &self,
+        tlg_template: &str,
+        output_dir: &Path,
+        headers: &[PathBuf],
+        prefix: Option<&str>,
+        suffix: Option<&str>,
+    ) -> Fallible<()> {
+        let includes = headers
+            .iter()
+            .map(|header| format!("#include \"{}\"", header.display()))
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        let tlg_header = read_to_string(tlg_template)?
+            .replace(TLG_ID_PREFIX, prefix.unwrap_or(""))
+            .replace(TLG_ID_INCLUDES, includes.as_str())
+            .replace(TLG_ID_SUFFIX, suffix.unwrap_or(""));
+
+        create_dir_all(output_dir)?;
+        File::create(output_dir.join(TLG_HEADER_NAME))?.write_all(tlg_header.as_bytes())?;
+
+        Ok(())
+    }
+
     /// Returns a reference to the synthesis `Stubs`.
     pub fn stubs(&self) -> &Stubs {
         &self.stubs
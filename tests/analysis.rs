@@ -11,9 +11,20 @@ multiline
 
 #[cfg(test)]
 mod analysis {
-    use thinlinelib::analysis::{Argument, Enum, Function};
+    use thinlinelib::{
+        analysis::{Analysis, Argument, Enum, Function},
+        Thinline,
+    };
     use MULTILINE_COMMENT;
 
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn send_sync() {
+        assert_send_sync::<Analysis>();
+        assert_send_sync::<Thinline>();
+    }
+
     #[test]
     fn argument() {
         // new
@@ -5,7 +5,7 @@ extern crate thinlinelib;
 mod c {
     use snapshot::snapshot;
     use std::path::Path;
-    use thinlinelib::{analysis::Analysis, entity::EntityType, language_type::C};
+    use thinlinelib::{analysis::{Analysis, Function}, entity::EntityType};
 
     #[test]
     fn collect_sources() {
@@ -14,7 +14,7 @@ mod c {
             // when_directory_is_valid()
             {
                 // Given
-                let analysis: Analysis<C> = Analysis::new();
+                let analysis = Analysis::new(&["c"]);
 
                 // When
                 let c_test_src_path = Path::new("tests").join("testdata").join("c_sources");
@@ -34,7 +34,7 @@ mod c {
             // when_directory_not_existing()
             {
                 // Given
-                let analysis: Analysis<C> = Analysis::new();
+                let analysis = Analysis::new(&["c"]);
 
                 // When
                 let c_test_src_path = Path::new("not").join("existing");
@@ -50,7 +50,7 @@ mod c {
             // when_path_is_no_directory()
             {
                 // Given
-                let analysis: Analysis<C> = Analysis::new();
+                let analysis = Analysis::new(&["c"]);
 
                 // When
                 let c_test_src_path = Path::new("tests").join("lib.rs");
@@ -65,8 +65,103 @@ mod c {
         }
     }
 
+    #[test]
+    fn extract_entities_in_parallel() {
+        let extract_entities = |jobs| {
+            let mut analysis = Analysis::new(&["c"]);
+            analysis.jobs = jobs;
+
+            let c_test_src_path = Path::new("tests").join("testdata").join("c_sources");
+            assert!(
+                analysis
+                    .collect_sources(&c_test_src_path, &[String::from(".")])
+                    .is_ok()
+            );
+            assert!(analysis.extract_entities().is_ok());
+
+            let files = analysis
+                .project_files()
+                .iter()
+                .map(|project_file| (project_file.path.clone(), project_file.entities().clone()))
+                .collect::<Vec<_>>();
+            files
+        };
+
+        assert_eq!(extract_entities(1), extract_entities(4));
+    }
+
+    #[test]
+    fn file_entities() {
+        let analysis = Analysis::new(&["c"]);
+        let c_test_src_path = Path::new("tests").join("testdata").join("c_sources");
+        assert!(
+            analysis
+                .collect_sources(&c_test_src_path, &[String::from(".")])
+                .is_ok()
+        );
+        assert!(analysis.extract_entities().is_ok());
+
+        let entities = analysis.file_entities(&c_test_src_path.join("test1.c"));
+        assert!(entities.is_some());
+        assert!(
+            entities
+                .unwrap()
+                .iter()
+                .any(|entity| entity.find("test_int_no1").is_some())
+        );
+        assert!(
+            analysis
+                .file_entities(&c_test_src_path.join("not_a_c.py"))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn merge_declarations() {
+        // Given
+        let analysis = Analysis::new(&["c"]);
+        let c_project_path = Path::new("examples").join("c_project");
+        assert!(
+            analysis
+                .collect_sources(&c_project_path, &[String::from("include"), String::from("src")])
+                .is_ok()
+        );
+
+        // When
+        assert!(analysis.extract_entities().is_ok());
+
+        // Then
+        let functions: Vec<Function> = analysis
+            .project_files()
+            .iter()
+            .flat_map(|project_file| {
+                project_file
+                    .entities()
+                    .iter()
+                    .flat_map(|entity| entity.all_functions().into_iter().cloned())
+                    .collect::<Vec<Function>>()
+            })
+            .filter(|function| function.name == "test_int_no1")
+            .collect();
+
+        assert_eq!(functions.len(), 1);
+        assert!(functions[0].description.is_some());
+        assert!(
+            functions[0]
+                .declaration
+                .as_ref()
+                .map_or(false, |location| location.path.ends_with("header1.h"))
+        );
+        assert!(
+            functions[0]
+                .definition
+                .as_ref()
+                .map_or(false, |location| location.path.ends_with("src1.c"))
+        );
+    }
+
     fn extract_entities_c() -> Vec<EntityType> {
-        let analysis: Analysis<C> = Analysis::new();
+        let analysis = Analysis::new(&["c"]);
         let c_test_src_path = Path::new("tests").join("testdata").join("analysis");
         assert!(
             analysis
@@ -5,7 +5,10 @@ extern crate thinlinelib;
 mod cpp {
     use snapshot::snapshot;
     use std::path::Path;
-    use thinlinelib::{analysis::Analysis, entity::EntityType, language_type::Cpp};
+    use thinlinelib::{
+        analysis::{Analysis, ArgumentKind, Linkage, Qualifier, StorageClass}, dsl,
+        entity::{Access, Entity, EntityKind, EntityType},
+    };
 
     #[test]
     fn cpp() {
@@ -14,7 +17,7 @@ mod cpp {
             // should_succeed when_directory_is_valid
             {
                 // Given
-                let analysis: Analysis<Cpp> = Analysis::new();
+                let analysis = Analysis::new(&["cpp"]);
 
                 // When
                 let cpp_test_src_path = Path::new("tests").join("testdata").join("cpp_sources");
@@ -33,7 +36,7 @@ mod cpp {
                 // when_directory_not_existing
                 {
                     // Given
-                    let analysis: Analysis<Cpp> = Analysis::new();
+                    let analysis = Analysis::new(&["cpp"]);
 
                     // When
                     let cpp_test_src_path = Path::new("not").join("existing");
@@ -49,7 +52,7 @@ mod cpp {
                 // when_path_is_no_directory
                 {
                     // Given
-                    let analysis: Analysis<Cpp> = Analysis::new();
+                    let analysis = Analysis::new(&["cpp"]);
 
                     // When
                     let cpp_test_src_path = Path::new("tests").join("lib.rs");
@@ -66,7 +69,7 @@ mod cpp {
     }
 
     fn extract_entities_cpp() -> Vec<EntityType> {
-        let analysis: Analysis<Cpp> = Analysis::new();
+        let analysis = Analysis::new(&["cpp"]);
         let cpp_test_src_path = Path::new("tests").join("testdata").join("analysis");
         assert!(
             analysis
@@ -96,4 +99,275 @@ mod cpp {
     fn extract_entities_windows_cpp() -> Vec<EntityType> {
         extract_entities_cpp()
     }
+
+    #[test]
+    fn entity_kinds() {
+        // Given
+        let analysis = Analysis::new(&["cpp"]);
+        let cpp_test_src_path = Path::new("tests").join("testdata").join("cpp_kinds");
+        assert!(
+            analysis
+                .collect_sources(&cpp_test_src_path, &[String::from(".")])
+                .is_ok()
+        );
+
+        // When
+        assert!(analysis.extract_entities().is_ok());
+
+        // Then
+        let index = analysis.project_files()[0].entities()[0].clone();
+        let entity = |qualified_name| match index.find(qualified_name) {
+            Some(EntityType::Entity(entity)) => entity.clone(),
+            _ => panic!("Entity '{}' not found.", qualified_name),
+        };
+        let access = |qualified_name| match index.find(qualified_name) {
+            Some(EntityType::Function(function)) => function.access.clone(),
+            _ => panic!("Function '{}' not found.", qualified_name),
+        };
+
+        assert_eq!(entity("shapes").kind, EntityKind::Namespace);
+
+        let shape: Entity = entity("shapes::shape");
+        assert_eq!(shape.kind, EntityKind::Class);
+        assert!(shape.is_abstract);
+        assert!(!shape.is_instantiable());
+        assert_eq!(access("shapes::shape::area"), Some(Access::Public));
+        assert_eq!(access("shapes::shape::id"), Some(Access::Protected));
+
+        let circle = entity("shapes::circle");
+        assert!(!circle.is_abstract);
+        assert_eq!(circle.bases.len(), 1);
+        assert!(circle.bases[0].ends_with("shape"));
+        assert_eq!(access("shapes::circle::radius"), Some(Access::Private));
+
+        assert_eq!(entity("shapes::point").kind, EntityKind::Struct);
+        assert_eq!(entity("shapes::value").kind, EntityKind::Union);
+    }
+
+    #[test]
+    fn templates() {
+        // Given
+        let analysis = Analysis::new(&["cpp"]);
+        let cpp_test_src_path = Path::new("tests").join("testdata").join("cpp_templates");
+        assert!(
+            analysis
+                .collect_sources(&cpp_test_src_path, &[String::from(".")])
+                .is_ok()
+        );
+
+        // When
+        assert!(analysis.extract_entities().is_ok());
+
+        // Then
+        let index = analysis.project_files()[0].entities()[0].clone();
+
+        match index.find("templates::add") {
+            Some(EntityType::Function(function)) => {
+                assert_eq!(function.template_parameters, vec!["T"]);
+                assert_eq!(function.arguments.len(), 2);
+                assert_eq!(function.return_type, Some(String::from("T")));
+            }
+            _ => panic!("Function template not found."),
+        }
+
+        let buffers: Vec<Entity> = match index.find("templates") {
+            Some(EntityType::Entity(namespace)) => namespace
+                .entities
+                .iter()
+                .filter_map(|entity| match entity {
+                    EntityType::Entity(entity) => Some(entity.clone()),
+                    _ => None,
+                })
+                .collect(),
+            _ => panic!("Namespace not found."),
+        };
+        assert_eq!(buffers.len(), 2);
+        assert_eq!(buffers[0].name, "buffer");
+        assert_eq!(buffers[0].template_parameters, vec!["T", "N"]);
+        assert!(buffers[1].name.starts_with("buffer<"));
+        assert_eq!(buffers[1].template_parameters, vec!["T"]);
+    }
+    #[test]
+    fn overloads() {
+        // Given
+        let analysis = Analysis::new(&["cpp"]);
+        let cpp_test_src_path = Path::new("tests").join("testdata").join("cpp_overloads");
+        assert!(
+            analysis
+                .collect_sources(&cpp_test_src_path, &[String::from(".")])
+                .is_ok()
+        );
+
+        // When
+        assert!(analysis.extract_entities().is_ok());
+
+        // Then
+        let index = analysis.project_files()[0].entities()[0].clone();
+        let functions = index.all_functions();
+
+        assert_eq!(functions.len(), 2);
+        assert_eq!(functions[0].signature(), "overloads::add(int, int)");
+        assert_eq!(functions[1].signature(), "overloads::add(double, double)");
+        assert!(functions[0].usr.is_some());
+        assert_ne!(functions[0].usr, functions[1].usr);
+
+        // The second call of the double overload can not be resolved.
+        let problems = dsl::validate_entity(&index);
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].contains("ambiguous"));
+    }
+    #[test]
+    fn qualifiers() {
+        // Given
+        let analysis = Analysis::new(&["cpp"]);
+        let cpp_test_src_path = Path::new("tests").join("testdata").join("cpp_qualifiers");
+        assert!(
+            analysis
+                .collect_sources(&cpp_test_src_path, &[String::from(".")])
+                .is_ok()
+        );
+
+        // When
+        assert!(analysis.extract_entities().is_ok());
+
+        // Then
+        let index = analysis.project_files()[0].entities()[0].clone();
+        let qualifiers = |signature: &str| {
+            index
+                .all_functions()
+                .into_iter()
+                .find(|function| function.signature() == signature)
+                .map(|function| function.qualifiers.clone())
+                .unwrap_or_else(|| panic!("Function '{}' not found.", signature))
+        };
+
+        assert_eq!(qualifiers("qualifiers::shape::shape()"), vec![Qualifier::Defaulted]);
+        assert_eq!(
+            qualifiers("qualifiers::shape::shape(const qualifiers::shape&)"),
+            vec![Qualifier::Deleted]
+        );
+        assert_eq!(
+            qualifiers("qualifiers::shape::~shape()"),
+            vec![Qualifier::Virtual, Qualifier::Noexcept]
+        );
+        assert_eq!(
+            qualifiers("qualifiers::shape::area()"),
+            vec![Qualifier::Virtual, Qualifier::PureVirtual, Qualifier::Const]
+        );
+        assert_eq!(qualifiers("qualifiers::shape::corners()"), vec![Qualifier::Constexpr]);
+        assert_eq!(
+            qualifiers("qualifiers::shape::scale(unsigned int)"),
+            vec![Qualifier::Inline, Qualifier::Const]
+        );
+
+        match index.find("qualifiers::shape::corners") {
+            Some(EntityType::Function(function)) => {
+                assert_eq!(function.storage_class, Some(StorageClass::Static));
+                assert_eq!(function.linkage, Some(Linkage::External));
+            }
+            _ => panic!("Function not found."),
+        }
+        match index.find("qualifiers::helper") {
+            Some(EntityType::Function(function)) => {
+                assert_eq!(function.storage_class, Some(StorageClass::Static));
+                assert_eq!(function.linkage, Some(Linkage::Internal));
+                assert!(function.untestable_reason().is_some());
+            }
+            _ => panic!("Function not found."),
+        }
+        match index.find("qualifiers::exported") {
+            Some(EntityType::Function(function)) => {
+                assert_eq!(function.storage_class, Some(StorageClass::Extern));
+                assert_eq!(function.linkage, Some(Linkage::External));
+            }
+            _ => panic!("Function not found."),
+        }
+    }
+
+    #[test]
+    fn file_types() {
+        let cpp_test_src_path = Path::new("tests").join("testdata").join("cpp_extensions");
+
+        // should_collect_all_default_extensions
+        {
+            // Given
+            let analysis = Analysis::new(&["cpp"]);
+
+            // When
+            assert!(
+                analysis
+                    .collect_sources(&cpp_test_src_path, &[String::from(".")])
+                    .is_ok()
+            );
+            assert!(analysis.extract_entities().is_ok());
+
+            // Then
+            let project_files = analysis.project_files();
+            assert_eq!(project_files.len(), 3);
+
+            // Headers are parsed as C++ within a pure C++ project.
+            let header = project_files
+                .iter()
+                .find(|project_file| project_file.path.ends_with("shape.h"))
+                .expect("Header not collected.");
+            match header.entities()[0].find("shapes::square") {
+                Some(EntityType::Entity(class)) => assert_eq!(class.kind, EntityKind::Class),
+                _ => panic!("Class not found."),
+            };
+        }
+
+        // should_only_collect_the_configured_extensions
+        {
+            // Given
+            let mut analysis = Analysis::new(&["cpp"]);
+            analysis.backends_mut().set_file_types("cpp", &["cc"]);
+
+            // When
+            assert!(
+                analysis
+                    .collect_sources(&cpp_test_src_path, &[String::from(".")])
+                    .is_ok()
+            );
+
+            // Then
+            let project_files = analysis.project_files();
+            assert_eq!(project_files.len(), 1);
+            assert!(project_files[0].path.ends_with("shape.cc"));
+        }
+    }
+    #[test]
+    fn arguments() {
+        // Given
+        let analysis = Analysis::new(&["cpp"]);
+        let cpp_test_src_path = Path::new("tests").join("testdata").join("cpp_arguments");
+        assert!(
+            analysis
+                .collect_sources(&cpp_test_src_path, &[String::from(".")])
+                .is_ok()
+        );
+
+        // When
+        assert!(analysis.extract_entities().is_ok());
+
+        // Then
+        let index = analysis.project_files()[0].entities()[0].clone();
+
+        match index.find("arguments::scale") {
+            Some(EntityType::Function(function)) => {
+                assert_eq!(function.arguments[0].value, None);
+                assert_eq!(function.arguments[1].value, Some(String::from("2")));
+            }
+            _ => panic!("Function not found."),
+        }
+        match index.find("arguments::sum") {
+            Some(EntityType::Function(function)) => {
+                assert_eq!(function.arguments.len(), 2);
+                assert_eq!(function.arguments[1].kind, ArgumentKind::Variadic);
+                assert_eq!(function.signature(), "arguments::sum(unsigned int, ...)");
+            }
+            _ => panic!("Function not found."),
+        }
+
+        assert!(dsl::validate_entity(&index).is_empty());
+    }
 }
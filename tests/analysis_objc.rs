@@ -0,0 +1,103 @@
+extern crate thinlinelib;
+
+#[cfg(test)]
+mod objc {
+    use std::path::Path;
+    use thinlinelib::{analysis::{Analysis, Function}, entity::{EntityKind, EntityType}};
+
+    #[test]
+    fn collect_sources() {
+        // Given
+        let analysis = Analysis::new(&["objc"]);
+
+        // When
+        let objc_test_src_path = Path::new("tests").join("testdata").join("objc_sources");
+        assert!(
+            analysis
+                .collect_sources(&objc_test_src_path, &[String::from(".")])
+                .is_ok()
+        );
+
+        // Then
+        assert_eq!(analysis.project_files().len(), 2);
+    }
+
+    #[test]
+    fn collect_headers() {
+        // Given
+        let analysis = Analysis::new(&["objc"]);
+
+        // When
+        let objc_test_src_path = Path::new("tests").join("testdata").join("objc_headers");
+        assert!(
+            analysis
+                .collect_sources(&objc_test_src_path, &[String::from(".")])
+                .is_ok()
+        );
+
+        // Then
+        assert_eq!(analysis.project_files().len(), 2);
+        assert_eq!(analysis.backend(Path::new("counter.h")).unwrap().name(), "objc");
+
+        // C keeps the headers within mixed projects.
+        let analysis = Analysis::new(&["c", "objc"]);
+        assert_eq!(analysis.backend(Path::new("counter.h")).unwrap().name(), "c");
+    }
+
+    #[test]
+    fn extract_entities() {
+        // Given
+        let analysis = Analysis::new(&["objc"]);
+        let objc_test_src_path = Path::new("tests").join("testdata").join("objc_sources");
+        assert!(
+            analysis
+                .collect_sources(&objc_test_src_path, &[String::from(".")])
+                .is_ok()
+        );
+
+        // When
+        assert!(analysis.extract_entities().is_ok());
+
+        // Then
+        let index = analysis.project_files()[0].entities()[0].clone();
+        match index.find("Calculator") {
+            Some(EntityType::Entity(class)) => {
+                assert_eq!(class.kind, EntityKind::Class);
+                assert_eq!(class.bases, vec!["Base"]);
+                assert!(class.description.is_some());
+
+                // The methods of the interface, implementation and category
+                // are merged into the class.
+                let methods: Vec<&str> = class
+                    .functions()
+                    .iter()
+                    .map(|method| method.name.as_str())
+                    .collect();
+                assert_eq!(methods, vec!["add:to:", "negate:", "scale:"]);
+            }
+            _ => panic!("Class not found."),
+        }
+
+        match index.find("Calculator::add:to:") {
+            Some(EntityType::Function(method)) => {
+                assert_eq!(method.return_type, Some(String::from("int")));
+                assert_eq!(method.arguments.len(), 2);
+                assert_eq!(method.arguments[1].name, "no2");
+                assert!(method.description.is_some());
+                assert!(method.declaration.is_some());
+                assert!(method.definition.is_some());
+            }
+            _ => panic!("Method not found."),
+        }
+
+        match index.find("square") {
+            Some(EntityType::Function(function)) => assert!(function.description.is_some()),
+            _ => panic!("Function not found."),
+        }
+
+        let counter_index = analysis.project_files()[1].entities()[0].clone();
+        let functions: Vec<Function> = counter_index.all_functions().into_iter().cloned().collect();
+        assert_eq!(functions.len(), 1);
+        assert_eq!(functions[0].qualified_name, "Counter::increment");
+    }
+}
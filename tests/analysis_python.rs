@@ -5,7 +5,9 @@ extern crate thinlinelib;
 mod cpp {
     use snapshot::snapshot;
     use std::path::Path;
-    use thinlinelib::{analysis::Analysis, entity::EntityType, language_type::Python};
+    use thinlinelib::{
+        analysis::{Analysis, ArgumentKind, ModulePath, Strictness}, dsl, entity::EntityType,
+    };
 
     #[test]
     fn python() {
@@ -14,7 +16,7 @@ mod cpp {
             // should_succeed when_directory_is_valid
             {
                 // Given
-                let analysis: Analysis<Python> = Analysis::new();
+                let analysis = Analysis::new(&["python"]);
 
                 // When
                 let python_test_src_path =
@@ -34,7 +36,7 @@ mod cpp {
                 // when_directory_not_existing
                 {
                     // Given
-                    let analysis: Analysis<Python> = Analysis::new();
+                    let analysis = Analysis::new(&["python"]);
 
                     // When
                     let python_test_src_path = Path::new("not").join("existing");
@@ -50,7 +52,7 @@ mod cpp {
                 // when_path_is_no_directory
                 {
                     // Given
-                    let analysis: Analysis<Python> = Analysis::new();
+                    let analysis = Analysis::new(&["python"]);
 
                     // When
                     let python_test_src_path = Path::new("tests").join("lib.rs");
@@ -66,8 +68,184 @@ mod cpp {
         }
     }
 
+    #[test]
+    fn arguments() {
+        // Given
+        let analysis = Analysis::new(&["python"]);
+        let py_test_src_path = Path::new("tests").join("testdata").join("python_arguments");
+        assert!(
+            analysis
+                .collect_sources(&py_test_src_path, &[String::from(".")])
+                .is_ok()
+        );
+
+        // When
+        assert!(analysis.extract_entities().is_ok());
+
+        // Then
+        let index = analysis.project_files()[0].entities()[0].clone();
+        let functions = index.functions();
+        assert_eq!(functions.len(), 2);
+
+        let arguments = &functions[0].arguments;
+        let names: Vec<&str> = arguments.iter().map(|arg| arg.name.as_str()).collect();
+        assert_eq!(names, vec!["value", "factor", "values", "offset", "options"]);
+        assert_eq!(arguments[0].value, None);
+        assert_eq!(arguments[1].value, Some(String::from("2")));
+        assert_eq!(arguments[2].kind, ArgumentKind::Variadic);
+        assert_eq!(arguments[3].value, Some(String::from("0")));
+        assert_eq!(arguments[3].kind, ArgumentKind::KeywordOnly);
+        assert_eq!(arguments[4].kind, ArgumentKind::Keywords);
+        assert_eq!(functions[0].return_type, None);
+
+        let arguments = &functions[1].arguments;
+        assert_eq!(arguments[0].atype, Some(String::from("int")));
+        assert_eq!(arguments[1].atype, Some(String::from("float")));
+        assert_eq!(arguments[1].value, Some(String::from("1.5")));
+        assert_eq!(arguments[2].name, "by");
+        assert_eq!(arguments[2].kind, ArgumentKind::KeywordOnly);
+        assert_eq!(functions[1].return_type, Some(String::from("float")));
+
+        assert_eq!(
+            functions[0].to_string(),
+            "scale(value, factor=2, *values, offset=0, **options)"
+        );
+        assert_eq!(
+            functions[1].to_string(),
+            "offset(value: int, factor: float = 1.5, *, by: int = 0) -> float"
+        );
+
+        assert!(dsl::validate_entity(&index).is_empty());
+    }
+
+    #[test]
+    fn docstrings() {
+        // Given
+        let analysis = Analysis::new(&["python"]);
+        let py_test_src_path = Path::new("tests").join("testdata").join("python_docstrings");
+        assert!(
+            analysis
+                .collect_sources(&py_test_src_path, &[String::from(".")])
+                .is_ok()
+        );
+
+        // When
+        assert!(analysis.extract_entities().is_ok());
+
+        // Then
+        let module = analysis.project_files()[0].entities()[0].clone();
+        assert_eq!(module.description.as_ref().unwrap().lines[0], "#SET_UP_CONTEXT:");
+
+        match module.find("docstrings::Calculator") {
+            Some(EntityType::Entity(class)) => {
+                assert_eq!(
+                    class.description.as_ref().unwrap().lines[0],
+                    "#TESTCLASS(Calculator)"
+                );
+                assert_eq!(class.qualified_name, "docstrings.Calculator");
+
+                let methods = class.functions();
+                assert_eq!(methods[0].qualified_name, "docstrings.Calculator.add");
+                assert_eq!(methods[0].decorators, vec!["staticmethod"]);
+                assert!(methods[0].description.is_some());
+                assert_eq!(methods[1].decorators, vec!["property"]);
+                assert!(methods[1].description.is_none());
+            }
+            _ => panic!("Class not found."),
+        }
+
+        match module.find("docstrings::fetch") {
+            Some(EntityType::Function(function)) => assert!(function.is_async),
+            _ => panic!("Function not found."),
+        }
+    }
+
+    #[test]
+    fn strictness() {
+        let extract_entities = |strictness| {
+            let mut analysis = Analysis::new(&["python"]);
+            analysis.strictness = strictness;
+
+            let py_test_src_path = Path::new("tests").join("testdata").join("python_broken");
+            assert!(
+                analysis
+                    .collect_sources(&py_test_src_path, &[String::from(".")])
+                    .is_ok()
+            );
+
+            analysis.extract_entities().map(|_| {
+                analysis
+                    .project_files()
+                    .iter()
+                    .map(|project_file| project_file.entities().len())
+                    .collect::<Vec<usize>>()
+            })
+        };
+
+        // should_fail in strict mode
+        {
+            let err = extract_entities(Strictness::Strict).unwrap_err();
+            assert!(
+                err.to_string()
+                    .contains("broken.py:10:1: Invalid python syntax.")
+            );
+        }
+
+        // should_skip_the_broken_file otherwise
+        {
+            assert_eq!(extract_entities(Strictness::Warn).unwrap(), vec![0, 1]);
+            assert_eq!(extract_entities(Strictness::Ignore).unwrap(), vec![0, 1]);
+        }
+    }
+
+    #[test]
+    fn module_paths() {
+        let module_paths = |module_root| {
+            let mut analysis = Analysis::new(&["python"]);
+            analysis.module_root = module_root;
+
+            let py_test_src_path = Path::new("tests").join("testdata").join("python_package");
+            assert!(
+                analysis
+                    .collect_sources(&py_test_src_path, &[String::from(".")])
+                    .is_ok()
+            );
+
+            let module_paths = analysis
+                .project_files()
+                .iter()
+                .map(|project_file| project_file.module_path.clone())
+                .collect::<Vec<Option<ModulePath>>>();
+            module_paths
+        };
+
+        // should_import_by_packages without module root
+        {
+            let module_paths = module_paths(None);
+            assert_eq!(module_paths[0].as_ref().unwrap().name, "pkg");
+
+            let module_path = module_paths[1].as_ref().unwrap();
+            assert_eq!(module_path.name, "pkg.calc");
+            assert!(module_path.root.ends_with("python_package"));
+        }
+
+        // should_import_relative_to_the_module_root
+        {
+            let module_root = Path::new("tests")
+                .join("testdata")
+                .join("python_package")
+                .join("pkg");
+            let module_paths = module_paths(Some(module_root.clone()));
+            assert!(module_paths[0].is_none());
+
+            let module_path = module_paths[1].as_ref().unwrap();
+            assert_eq!(module_path.name, "calc");
+            assert_eq!(module_path.root, module_root);
+        }
+    }
+
     fn extract_entities_python() -> Vec<EntityType> {
-        let analysis: Analysis<Python> = Analysis::new();
+        let analysis = Analysis::new(&["python"]);
         let py_test_src_path = Path::new("tests").join("testdata").join("analysis");
         assert!(
             analysis
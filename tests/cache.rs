@@ -0,0 +1,81 @@
+extern crate thinlinelib;
+
+#[cfg(test)]
+mod cache {
+    use std::{env, fs::read, path::Path};
+    use thinlinelib::{
+        analysis::Analysis, cache::{AnalysisCache, CACHE_FILE_NAME}, entity::Entity,
+    };
+
+    #[test]
+    fn save_and_load() {
+        // Given
+        let cache_file = env::temp_dir()
+            .join("thinline_cache_test")
+            .join(CACHE_FILE_NAME);
+        let existing_file = Path::new("tests").join("cache.rs");
+        let removed_file = Path::new("tests").join("removed.rs");
+
+        let mut cache = AnalysisCache::new();
+        cache.insert(&existing_file, String::from("hash"), vec![Entity::new("")]);
+        cache.insert(&removed_file, String::from("hash"), vec![Entity::new("")]);
+
+        // When
+        assert!(cache.save(&cache_file).is_ok());
+        let loaded_cache = AnalysisCache::load(&cache_file);
+
+        // Then
+        assert!(loaded_cache.get(&existing_file, "hash").is_some());
+        assert!(loaded_cache.get(&removed_file, "hash").is_none());
+    }
+
+    #[test]
+    fn hash_is_stable() {
+        let hash = AnalysisCache::hash(
+            "c",
+            &[b"int add(int a, int b);".to_vec()],
+            &[String::from("-Iinclude")],
+        );
+
+        assert_eq!(hash, "0456134ecc96a48b");
+    }
+
+    #[test]
+    fn load_when_cache_file_is_invalid() {
+        let cache = AnalysisCache::load(&Path::new("tests").join("cache.rs"));
+
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn extract_entities() {
+        // Given
+        let mut analysis = Analysis::new(&["c"]);
+        let c_test_src_path = Path::new("tests").join("testdata").join("analysis");
+        assert!(
+            analysis
+                .collect_sources(&c_test_src_path, &[String::from(".")])
+                .is_ok()
+        );
+
+        let project_file = analysis.project_files()[0].path.clone();
+        let hash = AnalysisCache::hash("c", &[read(&project_file).unwrap()], &[]);
+
+        let mut cache = AnalysisCache::new();
+        cache.insert(&project_file, hash.clone(), vec![Entity::new("cached")]);
+        analysis.set_cache(cache);
+
+        // When
+        assert!(analysis.extract_entities().is_ok());
+
+        // Then
+        assert_eq!(analysis.project_files()[0].entities()[0].name, "cached");
+        assert!(
+            analysis
+                .take_cache()
+                .unwrap()
+                .get(&project_file, &hash)
+                .is_some()
+        );
+    }
+}
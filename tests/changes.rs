@@ -0,0 +1,67 @@
+extern crate thinlinelib;
+
+#[cfg(test)]
+mod changes {
+    use std::path::{Path, PathBuf};
+    use thinlinelib::{
+        analysis::Function, changes::{self, Changes}, entity::{Entity, EntityType},
+    };
+
+    #[test]
+    fn since() {
+        assert!(Changes::since(Path::new("."), "HEAD").is_ok());
+        assert!(Changes::since(Path::new("."), "no_such_revision").is_err());
+    }
+
+    #[test]
+    fn affected_files() {
+        // Given
+        let c_sources_path = Path::new("tests").join("testdata").join("c_sources");
+        let files: Vec<PathBuf> = ["test1.c", "test1.h", "test2.c"]
+            .iter()
+            .map(|file| c_sources_path.join(file))
+            .collect();
+
+        let mut changes = Changes::default();
+        changes
+            .files
+            .push(c_sources_path.join("test1.h").canonicalize().unwrap());
+
+        // When
+        let affected_files = changes.affected_files(&files);
+
+        // Then
+        assert_eq!(affected_files, vec![files[0].clone(), files[1].clone()]);
+    }
+
+    #[test]
+    fn retain_changed() {
+        // Given
+        let mut previous = Entity::new("");
+        let mut class = Entity::new("Calculator");
+        class.add_entity::<Function>(EntityType::Function(Function::new("add")));
+        class.add_entity::<Function>(EntityType::Function(Function::new("sub")));
+        previous.add_entity::<Entity>(EntityType::Entity(class));
+        previous.add_entity::<Function>(EntityType::Function(Function::new("add")));
+
+        let mut current = previous.clone();
+        if let Some(EntityType::Entity(class)) = current.entities.get_mut(0) {
+            if let Some(EntityType::Function(add)) = class.entities.get_mut(0) {
+                add.set_return_type("int").unwrap();
+            }
+        }
+        let mut current = vec![current];
+
+        // When
+        changes::retain_changed(&mut current, &[previous]);
+
+        // Then
+        assert!(current[0].functions().is_empty());
+        if let Some(EntityType::Entity(class)) = current[0].entities.get(0) {
+            assert_eq!(class.functions().len(), 1);
+            assert_eq!(class.functions()[0].name, "add");
+        } else {
+            panic!("The class entity was removed.");
+        }
+    }
+}
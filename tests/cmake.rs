@@ -0,0 +1,69 @@
+extern crate thinlinelib;
+
+#[cfg(test)]
+mod cmake {
+    use std::path::{Path, PathBuf};
+    use thinlinelib::cmake::CMakeProject;
+
+    #[test]
+    fn render() {
+        // Given
+        let project_dir = Path::new("tests").join("testdata").join("cmake_project");
+        let mut cmake_project = CMakeProject::new("thinline_tests", project_dir.clone());
+        cmake_project.test_package = Some(String::from("GTest"));
+        cmake_project.test_libraries = vec![String::from("GTest::GTest")];
+        cmake_project.include_dirs = vec![String::from("include")];
+        cmake_project.lib_paths = vec![String::from("lib/libexample.a")];
+        cmake_project.test_sources = vec![
+            PathBuf::from("test_src1_c.cpp"),
+            PathBuf::from("test_src2_c.cpp"),
+        ];
+
+        // When
+        let cmake_lists = cmake_project.render();
+
+        // Then
+        let project_dir = project_dir.canonicalize().unwrap();
+        assert!(cmake_lists.contains("project(thinline_tests C CXX)"));
+        assert!(cmake_lists.contains(&format!(
+            "set(THINLINE_PROJECT_DIR \"{}\")",
+            project_dir.to_string_lossy().replace('\\', "/")
+        )));
+        assert!(cmake_lists.contains("find_package(GTest REQUIRED)"));
+        assert!(cmake_lists.contains("enable_testing()"));
+        assert!(cmake_lists.contains("include_directories(${THINLINE_PROJECT_DIR}/include)"));
+        assert!(cmake_lists.contains(
+            "target_link_libraries(test_src1_c GTest::GTest ${THINLINE_PROJECT_DIR}/lib/libexample.a)"
+        ));
+        assert!(cmake_lists.contains("add_test(NAME test_src1_c COMMAND test_src1_c)"));
+        assert!(cmake_lists.contains("add_test(NAME test_src2_c COMMAND test_src2_c)"));
+    }
+
+    #[test]
+    fn render_external_paths() {
+        // Given
+        let mut cmake_project = CMakeProject::new("thinline_tests", "/project");
+        cmake_project.include_dirs = vec![String::from("/usr/include/example")];
+        cmake_project.lib_paths = vec![String::from("/usr/lib/libexample.so"), String::from("m")];
+        cmake_project.test_sources = vec![PathBuf::from("test_src1_c.cpp")];
+
+        // When
+        let cmake_lists = cmake_project.render();
+
+        // Then
+        assert!(cmake_lists.contains("set(THINLINE_PROJECT_DIR \"/project\")"));
+        assert!(cmake_lists.contains("include_directories(/usr/include/example)"));
+        assert!(
+            cmake_lists.contains("target_link_libraries(test_src1_c /usr/lib/libexample.so m)")
+        );
+    }
+
+    #[test]
+    fn render_without_test_package() {
+        let cmake_project = CMakeProject::new("thinline_tests", "/project");
+        let cmake_lists = cmake_project.render();
+
+        assert!(!cmake_lists.contains("find_package"));
+        assert!(!cmake_lists.contains("add_executable"));
+    }
+}
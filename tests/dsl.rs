@@ -0,0 +1,193 @@
+extern crate thinlinelib;
+
+#[cfg(test)]
+mod dsl {
+    use thinlinelib::analysis::{Argument, ArgumentKind, Description, Function};
+    use thinlinelib::dsl::{self, FunctionCall, Statement};
+
+    #[test]
+    fn parse() {
+        // should_succeed
+        {
+            let mut description = Description::new();
+            description.set(
+                "
+                # TESTCLASS(c1)
+                # SET_UP_CONTEXT:
+                    this->class_inst = new c1();
+                # TESTCASE(c1::AddTwoNumbers)
+                    # EQ[this->class_inst->TL_FCT(no1: no1, no2: 10) => 15]
+                ",
+            );
+
+            let statements = dsl::parse(&description).unwrap();
+
+            assert_eq!(statements.len(), 5);
+            assert_eq!(statements[0], Statement::TestClass(String::from("c1")));
+            assert_eq!(statements[1], Statement::Context(String::from("SET_UP")));
+            assert_eq!(
+                statements[2],
+                Statement::Code(String::from("this->class_inst = new c1();"))
+            );
+            assert_eq!(
+                statements[3],
+                Statement::TestCase(String::from("c1::AddTwoNumbers"))
+            );
+
+            if let Statement::Assertion(assertion) = &statements[4] {
+                assert_eq!(assertion.comparison, "EQ");
+                assert_eq!(assertion.call.receiver, "this->class_inst->");
+                assert_eq!(assertion.call.arguments.len(), 2);
+                assert_eq!(assertion.call.arguments[0].name, "no1");
+                assert_eq!(assertion.call.arguments[0].value, Some(String::from("no1")));
+                assert_eq!(assertion.expected, "15");
+            } else {
+                panic!("Statement is no assertion.");
+            }
+        }
+
+        // should_fail
+        {
+            let mut description = Description::new();
+            description.set("# EQ[fct(no1: 1) => 15]");
+            assert!(dsl::parse(&description).is_err());
+
+            description.set("# EQ[TL_FCT(1) => 15]");
+            assert!(dsl::parse(&description).is_err());
+
+            description.set("# TESTSUITE(suite)");
+            assert!(dsl::parse(&description).is_err());
+        }
+    }
+
+    #[test]
+    fn validate_function() {
+        let mut function = Function::new("test_int_no1");
+        function.set_arguments(&vec![
+            Argument::new("no1", Some("int")),
+            Argument::new("no2", Some("int")),
+        ]);
+
+        // should_succeed
+        {
+            function.set_description(
+                "
+                # TESTCASE(Source1::CheckIfSumWorks)
+                    int test_no = 2;
+                    # EQ[TL_FCT(no1: test_no, no2: 5) => 7]
+                ",
+            );
+            assert!(dsl::validate_function(&function).is_empty());
+        }
+
+        // should_fail
+        {
+            function.set_description("# EQ[TL_FCT(no1: 1, no2: 5) => 7]");
+            assert_eq!(dsl::validate_function(&function).len(), 1);
+
+            function.set_description("# TESTCASE(case)\n# EQ[TL_FCT(no1: 1) => 7]");
+            assert_eq!(dsl::validate_function(&function).len(), 1);
+
+            function.set_description("# TESTCASE(case)\n# XY[TL_FCT(no1: 1, no2: 5) => 7]");
+            assert_eq!(dsl::validate_function(&function).len(), 1);
+
+            function.set_description("# TYPES(int, double)\n# TESTCASE(case)");
+            assert_eq!(dsl::validate_function(&function).len(), 1);
+        }
+    }
+
+    #[test]
+    fn optional_arguments() {
+        let mut no2 = Argument::new("no2", Some("int"));
+        no2.set_value("5");
+
+        let mut function = Function::new("sum");
+        function.set_arguments(&vec![
+            Argument::new("no1", Some("int")),
+            no2,
+            Argument::variadic("...", ArgumentKind::Variadic),
+        ]);
+
+        function.set_description("# TESTCASE(case)\n# EQ[TL_FCT(no1: 1) => 6]");
+        assert!(dsl::validate_function(&function).is_empty());
+
+        function.set_description("# TESTCASE(case)\n# EQ[TL_FCT(no2: 1) => 6]");
+        assert_eq!(dsl::validate_function(&function).len(), 1);
+
+        // Any argument is taken by variadic keyword arguments.
+        let mut function = Function::new("sum");
+        function.set_arguments(&vec![
+            Argument::new("no1", None),
+            Argument::variadic("kwargs", ArgumentKind::Keywords),
+        ]);
+
+        function.set_description("# TESTCASE(case)\n# EQ[TL_FCT(no1: 1, no2: 5) => 6]");
+        assert!(dsl::validate_function(&function).is_empty());
+    }
+
+    #[test]
+    fn types() {
+        assert_eq!(
+            Statement::parse("#TYPES(int,std::vector<int>)").unwrap(),
+            Statement::Types(vec![String::from("int"), String::from("std::vector<int>")])
+        );
+
+        let mut function = Function::new("add");
+        function.template_parameters.push(String::from("T"));
+        function.set_description("# TYPES(int, double)\n# TESTCASE(add)");
+
+        assert!(dsl::validate_function(&function).is_empty());
+        assert_eq!(
+            dsl::test_types(function.description.as_ref().unwrap()),
+            vec!["int", "double"]
+        );
+    }
+    #[test]
+    fn resolve_overloads() {
+        let mut add_int = Function::new("add");
+        add_int.set_arguments(&vec![
+            Argument::new("no1", Some("int")),
+            Argument::new("no2", Some("int")),
+        ]);
+        let mut add_str = Function::new("add");
+        add_str.set_arguments(&vec![
+            Argument::new("no1", Some("const std::string &")),
+            Argument::new("no2", Some("const std::string &")),
+        ]);
+        let mut add_three = Function::new("add");
+        add_three.set_arguments(&vec![
+            Argument::new("no1", Some("int")),
+            Argument::new("no2", Some("int")),
+            Argument::new("no3", Some("int")),
+        ]);
+        {
+            let overloads = vec![&add_int, &add_str, &add_three];
+
+            let resolve = |call: &str| {
+                FunctionCall::parse(call)
+                    .unwrap()
+                    .resolve(&overloads)
+                    .map(|function| function.signature())
+            };
+
+            assert_eq!(resolve("TL_FCT(no1: 1, no2: 2)").unwrap(), "add(int, int)");
+            assert_eq!(
+                resolve("TL_FCT(no1: \"a\", no2: \"b\")").unwrap(),
+                "add(const std::string&, const std::string&)"
+            );
+            assert_eq!(resolve("TL_FCT(no1: (int)x, no2: y)").unwrap(), "add(int, int)");
+            assert_eq!(
+                resolve("TL_FCT(no1: x, no2: y, no3: z)").unwrap(),
+                "add(int, int, int)"
+            );
+            assert!(resolve("TL_FCT(no1: x, no2: y)").is_err());
+            assert!(resolve("TL_FCT(no1: 1)").is_err());
+        }
+
+        // A call of another overload is reported.
+        add_int.set_description("# TESTCASE(add)\n# EQ[TL_FCT(no1: \"a\", no2: \"b\") => 3]");
+        let overloads = vec![&add_int, &add_str, &add_three];
+        assert_eq!(dsl::validate_overload(&add_int, &overloads).len(), 1);
+        assert!(dsl::validate_function(&add_int).is_empty());
+    }
+}
@@ -0,0 +1,50 @@
+extern crate serde_json;
+extern crate serde_yaml;
+extern crate thinlinelib;
+
+#[cfg(test)]
+mod dump {
+    use serde_json;
+    use serde_yaml;
+    use thinlinelib::{
+        analysis::{Function, ProjectFile}, dsl::Statement,
+        dump::{self, DumpFormat, FileDump}, entity::{Entity, EntityType},
+    };
+
+    fn project_files() -> Vec<ProjectFile> {
+        let mut function = Function::new("test_int_no1");
+        function.set_description("# TESTCASE(case)\n# EQ[TL_FCT() => 7]");
+
+        let mut index = Entity::new("");
+        index.add_entity::<Function>(EntityType::Function(function));
+
+        let project_file = ProjectFile::new("src/src1.c");
+        project_file.entities_mut().push(index);
+
+        vec![project_file]
+    }
+
+    #[test]
+    fn json() {
+        let dumped = dump::dump(&project_files(), &DumpFormat::Json).unwrap();
+        let file_dumps: Vec<FileDump> = serde_json::from_str(&dumped).unwrap();
+
+        assert_eq!(file_dumps.len(), 1);
+        assert_eq!(file_dumps[0].entities[0].functions()[0].name, "test_int_no1");
+        assert_eq!(file_dumps[0].descriptions.len(), 1);
+        assert_eq!(file_dumps[0].descriptions[0].entity, "test_int_no1");
+        assert_eq!(
+            file_dumps[0].descriptions[0].statements[0],
+            Statement::TestCase(String::from("case"))
+        );
+    }
+
+    #[test]
+    fn yaml() {
+        let dumped = dump::dump(&project_files(), &DumpFormat::Yaml).unwrap();
+        let file_dumps: Vec<FileDump> = serde_yaml::from_str(&dumped).unwrap();
+
+        assert_eq!(file_dumps.len(), 1);
+        assert_eq!(file_dumps[0].descriptions[0].statements.len(), 2);
+    }
+}
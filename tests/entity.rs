@@ -12,7 +12,7 @@ multiline
 #[cfg(test)]
 mod entity {
     use thinlinelib::analysis::{Enum, Function};
-    use thinlinelib::entity::{Entity, EntityType};
+    use thinlinelib::entity::{Entity, EntityKind, EntityType, EntityVisitor};
     use MULTILINE_COMMENT;
 
     #[test]
@@ -93,4 +93,125 @@ mod entity {
             assert_eq!(entity_desc[4], "comment");
         }
     }
+
+    fn entity_tree() -> Entity {
+        let mut class = Entity::new("c1");
+        let mut add = Function::new("add_two_numbers");
+        add.set_description("# TESTCASE(add)");
+        class.add_entity::<Function>(EntityType::Function(add));
+        class.add_entity::<Enum>(EntityType::Enum(Enum::new("mode")));
+
+        let mut namespace = Entity::new("ns1");
+        namespace.add_entity::<Entity>(EntityType::Entity(class));
+        namespace.add_entity::<Function>(EntityType::Function(Function::new("sub")));
+
+        let mut index = Entity::new("");
+        index.add_entity::<Entity>(EntityType::Entity(namespace));
+        index
+    }
+
+    #[test]
+    fn walk() {
+        struct Recorder {
+            events: Vec<String>,
+        }
+
+        impl<'a> EntityVisitor<'a> for Recorder {
+            fn enter(&mut self, entity: &'a EntityType, parents: &[&'a str]) {
+                self.events
+                    .push(format!("enter {} in [{}]", entity.name(), parents.join(",")));
+            }
+
+            fn leave(&mut self, entity: &'a EntityType, _parents: &[&'a str]) {
+                self.events.push(format!("leave {}", entity.name()));
+            }
+        }
+
+        let mut recorder = Recorder { events: Vec::new() };
+        entity_tree().walk(&mut recorder);
+
+        assert_eq!(
+            recorder.events,
+            vec![
+                "enter ns1 in []",
+                "enter c1 in [ns1]",
+                "enter add_two_numbers in [ns1,c1]",
+                "leave add_two_numbers",
+                "enter mode in [ns1,c1]",
+                "leave mode",
+                "leave c1",
+                "enter sub in [ns1]",
+                "leave sub",
+                "leave ns1",
+            ]
+        );
+    }
+
+    #[test]
+    fn qualified_functions() {
+        let entity = entity_tree();
+        let functions: Vec<String> = entity
+            .qualified_functions()
+            .into_iter()
+            .map(|(qualified_name, _)| qualified_name)
+            .collect();
+
+        assert_eq!(functions, vec!["ns1::c1::add_two_numbers", "ns1::sub"]);
+        assert_eq!(entity.all_functions().len(), 2);
+        assert_eq!(entity.functions().len(), 0);
+    }
+
+    #[test]
+    fn described_functions() {
+        let entity = entity_tree();
+        let functions = entity.described_functions();
+
+        assert_eq!(functions.len(), 1);
+        assert_eq!(functions[0].name, "add_two_numbers");
+    }
+
+    #[test]
+    fn find() {
+        let entity = entity_tree();
+
+        match entity.find("ns1::c1::add_two_numbers") {
+            Some(EntityType::Function(function)) => assert_eq!(function.name, "add_two_numbers"),
+            _ => panic!("Function not found."),
+        }
+        match entity.find("ns1::c1::mode") {
+            Some(EntityType::Enum(enumeration)) => assert_eq!(enumeration.name, "mode"),
+            _ => panic!("Enum not found."),
+        }
+        assert!(entity.find("c1::add_two_numbers").is_none());
+        assert!(entity.find("ns1::c2").is_none());
+    }
+
+    #[test]
+    fn qualify() {
+        let mut entity = entity_tree();
+        entity.qualify();
+
+        match entity.find("ns1::c1") {
+            Some(EntityType::Entity(class)) => {
+                assert_eq!(class.qualified_name, "ns1::c1");
+                assert_eq!(class.all_functions()[0].qualified_name, "ns1::c1::add_two_numbers");
+            }
+            _ => panic!("Class not found."),
+        }
+        match entity.find("ns1::c1::mode") {
+            Some(EntityType::Enum(enumeration)) => {
+                assert_eq!(enumeration.qualified_name, "ns1::c1::mode")
+            }
+            _ => panic!("Enum not found."),
+        }
+    }
+
+    #[test]
+    fn with_kind() {
+        assert_eq!(Entity::new("ent").kind, EntityKind::Index);
+        assert_eq!(
+            Entity::with_kind("ns1", EntityKind::Namespace).kind,
+            EntityKind::Namespace
+        );
+    }
 }
@@ -2,11 +2,8 @@ extern crate thinlinelib;
 
 #[cfg(test)]
 mod lib {
-    use std::path::Path;
-    use thinlinelib::{
-        language_type::{Cpp, Python, C},
-        Thinline,
-    };
+    use std::{fs::read_to_string, path::Path};
+    use thinlinelib::Thinline;
 
     #[test]
     fn analyze() {
@@ -14,15 +11,13 @@ mod lib {
         {
             // Should succeed
             {
-                let mut thinline: Thinline<C> =
-                    Thinline::new(Path::new("examples").join("c_project"));
+                let mut thinline = Thinline::new(Path::new("examples").join("c_project"));
                 assert!(thinline.analyze("thinline.yml", true).is_ok());
             }
 
             // Should fail
             {
-                let mut thinline: Thinline<C> =
-                    Thinline::new(Path::new("non_existing").join("path"));
+                let mut thinline = Thinline::new(Path::new("non_existing").join("path"));
                 assert!(thinline.analyze("thinline.yml", true).is_err());
             }
         }
@@ -31,15 +26,13 @@ mod lib {
         {
             // Should succeed
             {
-                let mut thinline: Thinline<Cpp> =
-                    Thinline::new(Path::new("examples").join("cpp_project"));
+                let mut thinline = Thinline::new(Path::new("examples").join("cpp_project"));
                 assert!(thinline.analyze("thinline.yml", true).is_ok());
             }
 
             // Should fail
             {
-                let mut thinline: Thinline<Cpp> =
-                    Thinline::new(Path::new("non_existing").join("path"));
+                let mut thinline = Thinline::new(Path::new("non_existing").join("path"));
                 assert!(thinline.analyze("thinline.yml", true).is_err());
             }
         }
@@ -48,32 +41,47 @@ mod lib {
         {
             // Should succeed with dir
             {
-                let mut thinline: Thinline<Python> =
-                    Thinline::new(Path::new("examples").join("python_project"));
+                let mut thinline = Thinline::new(Path::new("examples").join("python_project"));
                 assert!(thinline.analyze("thinline.yml", true).is_ok());
             }
 
             // Should succeed with file
             {
-                let mut thinline: Thinline<Python> =
+                let mut thinline =
                     Thinline::new(Path::new("examples").join("python_project").join("src1.py"));
                 assert!(thinline.analyze("thinline.yml", false).is_ok());
             }
 
             // Should fail
             {
-                let mut thinline: Thinline<Python> =
-                    Thinline::new(Path::new("non_existing").join("path"));
+                let mut thinline = Thinline::new(Path::new("non_existing").join("path"));
                 assert!(thinline.analyze("thinline.yml", true).is_err());
             }
         }
     }
 
+    #[test]
+    fn set_languages() {
+        // Should succeed with a language of the project config
+        {
+            let mut thinline = Thinline::new(Path::new("examples").join("c_project"));
+            thinline.set_languages(&["c"]);
+            assert!(thinline.analyze("thinline.yml", false).is_ok());
+        }
+
+        // Should fail with a language differing from the project config
+        {
+            let mut thinline = Thinline::new(Path::new("examples").join("c_project"));
+            thinline.set_languages(&["python"]);
+            assert!(thinline.analyze("thinline.yml", false).is_err());
+        }
+    }
+
     #[test]
     fn synthesize() {
         // Test C synthesis
         {
-            let mut thinline: Thinline<C> = Thinline::new(Path::new("examples").join("c_project"));
+            let mut thinline = Thinline::new(Path::new("examples").join("c_project"));
 
             // Should succeed
             {
@@ -96,8 +104,7 @@ mod lib {
 
         // Test C++ analysis
         {
-            let mut thinline: Thinline<Cpp> =
-                Thinline::new(Path::new("examples").join("cpp_project"));
+            let mut thinline = Thinline::new(Path::new("examples").join("cpp_project"));
 
             // Should succeed
             {
@@ -120,8 +127,7 @@ mod lib {
 
         // Test Python analysis
         {
-            let mut thinline: Thinline<Python> =
-                Thinline::new(Path::new("examples").join("python_project"));
+            let mut thinline = Thinline::new(Path::new("examples").join("python_project"));
 
             // Should succeed
             {
@@ -142,4 +148,91 @@ mod lib {
             }
         }
     }
+
+    #[test]
+    fn synthesize_mixed_project() {
+        // Given
+        let project_dir = Path::new("tests").join("testdata").join("mixed_project");
+        let mut thinline = Thinline::new(&project_dir);
+        thinline.set_cache(false);
+        assert!(thinline.analyze("thinline.yml", false).is_ok());
+
+        // When
+        assert!(
+            thinline
+                .synthesize(Path::new("stubs").join("environment"))
+                .is_ok()
+        );
+
+        // Then
+        let c_output_dir = thinline.test_output_dir("c");
+        let cpp_output_dir = thinline.test_output_dir("cpp");
+        assert_eq!(c_output_dir, project_dir.join(".thinline").join("c"));
+        assert_eq!(cpp_output_dir, project_dir.join(".thinline").join("cpp"));
+
+        assert!(c_output_dir.join("test_src_calc_c.cpp").is_file());
+        assert!(cpp_output_dir.join("test_src_shapes_cpp.cpp").is_file());
+
+        let c_tlg_header = read_to_string(c_output_dir.join("__tlg__.h")).unwrap();
+        let cpp_tlg_header = read_to_string(cpp_output_dir.join("__tlg__.h")).unwrap();
+        assert!(c_tlg_header.contains("calc.h"));
+        assert!(!c_tlg_header.contains("shapes.hpp"));
+        assert!(cpp_tlg_header.contains("shapes.hpp"));
+        assert!(!cpp_tlg_header.contains("calc.h"));
+
+        assert!(c_output_dir.join("CMakeLists.txt").is_file());
+        assert!(cpp_output_dir.join("CMakeLists.txt").is_file());
+    }
+
+    #[test]
+    fn test_cases() {
+        let mut thinline = Thinline::new(Path::new("examples").join("c_project"));
+        assert!(thinline.analyze("thinline.yml", false).is_ok());
+
+        let test_cases = thinline.test_cases();
+        assert!(
+            test_cases
+                .iter()
+                .any(|(_, function, test_case)| function == "test_int_no1"
+                    && test_case == "Source1::CheckIfSumWorks")
+        );
+    }
+
+    #[test]
+    fn reanalyze() {
+        let project_dir = Path::new("examples").join("c_project");
+        let mut thinline = Thinline::new(&project_dir);
+        assert!(thinline.analyze("thinline.yml", false).is_ok());
+
+        // The unchanged functions of a changed file are dropped
+        {
+            assert!(
+                thinline
+                    .reanalyze(&[project_dir.join("src").join("src1.c")])
+                    .is_ok()
+            );
+            assert!(thinline.test_cases().is_empty());
+        }
+
+        // Files including a changed header are analyzed completely
+        {
+            assert!(
+                thinline
+                    .reanalyze(&[project_dir.join("include").join("header1.h")])
+                    .is_ok()
+            );
+            assert!(
+                thinline
+                    .test_cases()
+                    .iter()
+                    .any(|(_, function, _)| function == "test_int_no1")
+            );
+        }
+    }
+
+    #[test]
+    fn check() {
+        let mut thinline = Thinline::new(Path::new("examples").join("cpp_project"));
+        assert!(thinline.check("thinline.yml").is_ok());
+    }
 }
@@ -7,7 +7,7 @@ mod project_parameters {
     #[cfg(test)]
     mod should_succeed {
         use std::path::Path;
-        use thinlinelib::project_parameters::ProjectParameters;
+        use thinlinelib::project_parameters::{BuildBackend, ProjectParameters};
 
         #[test]
         fn when_yaml_is_valid() {
@@ -21,6 +21,7 @@ mod project_parameters {
 
             let parameters = parameters_res.unwrap();
 
+            assert_eq!(parameters.languages, vec![String::from("c")]);
             assert_eq!(parameters.test_env, "ctest");
 
             assert!(parameters.build_script.windows.is_empty());
@@ -32,6 +33,89 @@ mod project_parameters {
             );
 
             assert_eq!(parameters.lib_paths, vec![String::from("build/libtest.so")]);
+            assert_eq!(parameters.output_dir, ".thinline");
+            assert_eq!(parameters.build_backend, BuildBackend::CMake);
+        }
+
+        #[test]
+        fn when_compiler_backend_is_configured() {
+            let test_yml_path = Path::new("tests")
+                .join("testdata")
+                .join("config")
+                .join("config5.yml");
+            let parameters_res = ProjectParameters::parse(test_yml_path.to_str().unwrap());
+
+            assert!(parameters_res.is_ok());
+
+            let parameters = parameters_res.unwrap();
+
+            assert_eq!(parameters.build_backend, BuildBackend::Compiler);
+            assert_eq!(parameters.compiler.cc, None);
+            assert_eq!(parameters.compiler.cxx, Some(String::from("clang++")));
+            assert_eq!(
+                parameters.compiler.flags,
+                vec![String::from("-std=c++11"), String::from("-Wall")]
+            );
+            assert_eq!(
+                parameters.analysis_flags,
+                vec![String::from("-DTHINLINE_ANALYSIS")]
+            );
+        }
+
+        #[test]
+        fn when_multiple_languages_are_configured() {
+            let test_yml_path = Path::new("tests")
+                .join("testdata")
+                .join("config")
+                .join("config7.yml");
+            let parameters_res = ProjectParameters::parse(test_yml_path.to_str().unwrap());
+
+            assert!(parameters_res.is_ok());
+
+            let parameters = parameters_res.unwrap();
+
+            assert_eq!(
+                parameters.languages,
+                vec![String::from("c"), String::from("cpp")]
+            );
+            assert_eq!(parameters.module_root, None);
+        }
+
+        #[test]
+        fn when_module_root_is_configured() {
+            let test_yml_path = Path::new("tests")
+                .join("testdata")
+                .join("config")
+                .join("config8.yml");
+            let parameters_res = ProjectParameters::parse(test_yml_path.to_str().unwrap());
+
+            assert!(parameters_res.is_ok());
+
+            let parameters = parameters_res.unwrap();
+
+            assert_eq!(parameters.languages, vec![String::from("python")]);
+            assert_eq!(parameters.module_root, Some(String::from("src")));
+        }
+
+        #[test]
+        fn when_file_types_are_configured() {
+            let test_yml_path = Path::new("tests")
+                .join("testdata")
+                .join("config")
+                .join("config9.yml");
+            let parameters_res = ProjectParameters::parse(test_yml_path.to_str().unwrap());
+
+            assert!(parameters_res.is_ok());
+
+            let parameters = parameters_res.unwrap();
+
+            assert_eq!(
+                parameters.file_types,
+                vec![
+                    (String::from("cpp"), vec![String::from("cpp"), String::from("h")]),
+                    (String::from("c"), vec![String::from("c")]),
+                ]
+            );
         }
     }
 
@@ -61,6 +145,17 @@ mod project_parameters {
             assert!(parameters_res.is_err());
         }
 
+        #[test]
+        fn when_build_backend_is_unknown() {
+            let test_yml_path = Path::new("tests")
+                .join("testdata")
+                .join("config")
+                .join("config6.yml");
+            let parameters_res = ProjectParameters::parse(test_yml_path.to_str().unwrap());
+
+            assert!(parameters_res.is_err());
+        }
+
         #[test]
         fn when_no_test_env_could_be_parsed() {
             let test_yml_path = Path::new("tests")
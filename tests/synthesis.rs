@@ -0,0 +1,427 @@
+extern crate thinlinelib;
+
+#[cfg(test)]
+mod synthesis {
+    use std::path::{Path, PathBuf};
+    use thinlinelib::{
+        analysis::{Analysis, Argument, Function, Linkage, Location, ModulePath, ProjectFile},
+        entity::{Entity, EntityKind, EntityType}, stubs::Stub,
+        synthesis::Synthesis,
+    };
+
+    fn synthesis(test_env: &str) -> Synthesis {
+        let mut synthesis = Synthesis::new();
+        assert!(
+            synthesis
+                .parse_stubs(
+                    "./stubs/environment/env_stubs.yml",
+                    test_env,
+                    &Path::new("stubs").join("environment"),
+                )
+                .is_ok()
+        );
+
+        synthesis
+    }
+
+    #[test]
+    fn process_typed_function() {
+        // Given
+        let mut function = Function::new("add");
+        function.template_parameters.push(String::from("T"));
+        function.set_description(
+            "
+            # TYPES(int, double)
+            # TESTCASE(templates::Add)
+                EXPECT_EQ(TypeParam(3), add<TypeParam>(1, 2));
+            ",
+        );
+
+        let mut namespace = Entity::with_kind("templates", EntityKind::Namespace);
+        namespace.add_entity::<Function>(EntityType::Function(function));
+
+        let mut index = Entity::new("");
+        index.add_entity::<Entity>(EntityType::Entity(namespace));
+        index.qualify();
+
+        let project_file = ProjectFile::new("templates.hpp");
+        project_file.entities_mut().push(index);
+
+        let mut synthesis = synthesis("google_test");
+
+        // When
+        assert!(synthesis.process_testfile(&project_file).is_ok());
+
+        // Then
+        let test_file = &synthesis.test_files[0];
+        assert_eq!(test_file.test_classes.len(), 1);
+        assert!(
+            test_file.test_classes[0]
+                .contains("typedef ::testing::Types<int, double> templates_addTypes;")
+        );
+        assert_eq!(test_file.test_cases.len(), 1);
+        assert!(
+            test_file.test_cases[0]
+                .content
+                .starts_with("TYPED_TEST(templates_addTest, add)")
+        );
+    }
+
+    #[test]
+    fn process_overloads() {
+        // Given
+        let mut namespace = Entity::with_kind("overloads", EntityKind::Namespace);
+        for atype in &["int", "double"] {
+            let mut function = Function::new("add");
+            function.set_arguments(&vec![
+                Argument::new("a", Some(*atype)),
+                Argument::new("b", Some(*atype)),
+            ]);
+            function.set_description(&format!(
+                "
+                # TESTCASE(overloads::Add)
+                    EXPECT_EQ(3, add(({0})1, ({0})2));
+                ",
+                atype
+            ));
+            namespace.add_entity::<Function>(EntityType::Function(function));
+        }
+
+        let mut index = Entity::new("");
+        index.add_entity::<Entity>(EntityType::Entity(namespace));
+        index.qualify();
+
+        let project_file = ProjectFile::new("overloads.hpp");
+        project_file.entities_mut().push(index);
+
+        let mut synthesis = synthesis("google_test");
+
+        // When
+        assert!(synthesis.process_testfile(&project_file).is_ok());
+
+        // Then
+        let test_cases = &synthesis.test_files[0].test_cases;
+        assert_eq!(test_cases.len(), 2);
+
+        let test_names: Vec<&str> = test_cases
+            .iter()
+            .map(|test_case| test_case.content.lines().next().unwrap())
+            .collect();
+        assert!(test_names[0].starts_with("TEST_F(overloadsTest, add_"));
+        assert!(test_names[1].starts_with("TEST_F(overloadsTest, add_"));
+        assert_ne!(test_names[0], test_names[1]);
+    }
+
+    #[test]
+    fn process_same_named_sources() {
+        // Given
+        let project_dir = Path::new("project");
+        let project_files: Vec<ProjectFile> = ["a", "b"]
+            .iter()
+            .map(|dir| {
+                let mut function = Function::new("helper");
+                function.set_description(
+                    "
+                    # TESTCASE(Util::Helper)
+                        EXPECT_EQ(1, helper(1));
+                    ",
+                );
+
+                let mut index = Entity::new("");
+                index.add_entity::<Function>(EntityType::Function(function));
+
+                let project_file = ProjectFile::new(project_dir.join(dir).join("util.c"));
+                project_file.entities_mut().push(index);
+                project_file
+            })
+            .collect();
+
+        let mut synthesis = synthesis("google_test");
+        synthesis.project_dir = Some(project_dir.to_path_buf());
+
+        // When
+        for project_file in &project_files {
+            assert!(synthesis.process_testfile(project_file).is_ok());
+        }
+
+        // Then
+        assert_eq!(synthesis.test_files[0].path, Path::new("test_a_util_c.cpp"));
+        assert_eq!(synthesis.test_files[1].path, Path::new("test_b_util_c.cpp"));
+    }
+
+    #[test]
+    fn skip_untestable_function() {
+        // Given
+        let mut function = Function::new("helper");
+        function.linkage = Some(Linkage::Internal);
+        function.definition = Some(Location {
+            path: PathBuf::from("source1.c"),
+            line: 3,
+            column: 12,
+        });
+        function.set_description(
+            "
+            # TESTCASE(Source1::Helper)
+                EXPECT_EQ(1, helper(1));
+            ",
+        );
+
+        let mut index = Entity::new("");
+        index.add_entity::<Function>(EntityType::Function(function));
+
+        let project_file = ProjectFile::new("source1.c");
+        project_file.entities_mut().push(index);
+
+        let mut synthesis = synthesis("google_test");
+
+        // When
+        assert!(synthesis.process_testfile(&project_file).is_ok());
+
+        // Then
+        assert!(synthesis.test_files.is_empty());
+    }
+
+    #[test]
+    fn process_assertions() {
+        // Given
+        let mut function = Function::new("test_int_no1");
+        function.set_arguments(&vec![
+            Argument::new("no1", Some("int")),
+            Argument::new("no2", Some("int")),
+        ]);
+        function.set_description(
+            "
+            # TESTCASE(Source1::TestIntNo1)
+                int test_no = 2;
+                # EQ[TL_FCT(no2: 5, no1: test_no) => 7]
+                # LT[TL_FCT(no1: 5, no2: 2) => 8]
+            ",
+        );
+
+        let mut index = Entity::new("");
+        index.add_entity::<Function>(EntityType::Function(function));
+        index.qualify();
+
+        let project_file = ProjectFile::new("source1.c");
+        project_file.entities_mut().push(index);
+
+        let mut synthesis = synthesis("google_test");
+
+        // When
+        assert!(synthesis.process_testfile(&project_file).is_ok());
+
+        // Then
+        assert!(synthesis.test_files[0].test_cases[0].content.contains(
+            "int test_no = 2;\n    \
+             EXPECT_EQ(test_int_no1(test_no, 5), 7);\n    \
+             EXPECT_LT(test_int_no1(5, 2), 8);"
+        ));
+    }
+
+    #[test]
+    fn process_python_assertions() {
+        // Given
+        let mut method = Function::new("add");
+        method.language = Some(String::from("python"));
+        method.set_arguments(&vec![Argument::new("no1", None), Argument::new("no2", None)]);
+        method.set_description(
+            "
+            # TESTCASE(Calc::Add)
+                # NE[TL_FCT(no1: 1, no2: 2) => 4]
+            ",
+        );
+
+        let mut class = Entity::with_kind("Calculator", EntityKind::Class);
+        class.add_entity::<Function>(EntityType::Function(method));
+
+        let mut module = Entity::with_kind("calc", EntityKind::Module);
+        module.add_entity::<Entity>(EntityType::Entity(class));
+        module.qualify_with(".");
+
+        let project_file = ProjectFile::new("calc.py");
+        project_file.entities_mut().push(module);
+
+        let mut synthesis = synthesis("py_test");
+
+        // When
+        assert!(synthesis.process_testfile(&project_file).is_ok());
+
+        // Then
+        assert!(
+            synthesis.test_files[0].test_cases[0]
+                .content
+                .contains("    assert Calculator.add(no1=1, no2=2) != 4")
+        );
+    }
+
+    #[test]
+    fn fail_on_unrenderable_assertion() {
+        // Given
+        let mut function = Function::new("scale");
+        let mut factor = Argument::new("factor", Some("int"));
+        factor.set_value("2");
+        let mut offset = Argument::new("offset", Some("int"));
+        offset.set_value("0");
+        function.set_arguments(&vec![factor, offset]);
+        function.set_description(
+            "
+            # TESTCASE(Source1::Scale)
+                # EQ[TL_FCT(offset: 1) => 3]
+            ",
+        );
+
+        let mut index = Entity::new("");
+        index.add_entity::<Function>(EntityType::Function(function));
+        index.qualify();
+
+        let project_file = ProjectFile::new("source1.c");
+        project_file.entities_mut().push(index);
+
+        let mut synthesis = synthesis("google_test");
+
+        // When
+        let result = synthesis.process_testfile(&project_file);
+
+        // Then
+        assert!(result.is_err());
+        assert!(synthesis.test_files.is_empty());
+    }
+
+    #[test]
+    fn import_python_module() {
+        // Given
+        let mut function = Function::new("add");
+        function.set_description(
+            "
+            # TESTCASE(Calc::Add)
+                assert add(1, 2) == 3
+            ",
+        );
+
+        let mut module = Entity::with_kind("calc", EntityKind::Module);
+        module.add_entity::<Function>(EntityType::Function(function));
+        module.add_entity::<Entity>(EntityType::Entity(Entity::with_kind(
+            "Calculator",
+            EntityKind::Class,
+        )));
+        module.qualify();
+
+        let mut project_file = ProjectFile::new(Path::new("src").join("pkg").join("calc.py"));
+        project_file.module_path = Some(ModulePath {
+            root: Path::new("python_src").to_path_buf(),
+            name: String::from("pkg.calc"),
+        });
+        project_file.entities_mut().push(module);
+
+        let mut synthesis = synthesis("py_test");
+
+        // When
+        assert!(synthesis.process_testfile(&project_file).is_ok());
+
+        // Then
+        let test_file = &synthesis.test_files[0];
+        assert_eq!(test_file.path, Path::new("test_calc_py.py"));
+        assert!(
+            test_file.test_cases[0]
+                .content
+                .starts_with("def test_calc_add(calc_fixture):")
+        );
+
+        let rendered = test_file
+            .render(&Stub::from_str("#TEST_IMPORTS"))
+            .unwrap()
+            .unwrap();
+        assert!(rendered.starts_with("sys.path.insert(0, r\"python_src\")"));
+        assert!(rendered.contains("import pkg.calc\n"));
+        assert!(rendered.contains("from pkg.calc import add, Calculator\n"));
+    }
+
+    #[test]
+    fn process_python_docstrings() {
+        // Given
+        let analysis = Analysis::new(&["python"]);
+        let py_test_src_path = Path::new("tests").join("testdata").join("python_docstrings");
+        assert!(
+            analysis
+                .collect_sources(&py_test_src_path, &[String::from(".")])
+                .is_ok()
+        );
+        assert!(analysis.extract_entities().is_ok());
+
+        let mut synthesis = synthesis("py_test");
+
+        // When
+        assert!(
+            synthesis
+                .process_testfile(&analysis.project_files()[0])
+                .is_ok()
+        );
+
+        // Then
+        let rendered = synthesis.test_files[0]
+            .render(synthesis.stubs().file.as_ref().unwrap())
+            .unwrap()
+            .unwrap();
+
+        // The module docstring sets up the whole test file.
+        assert!(rendered.contains("\nimport math\n"));
+
+        // The class docstring defines the fixture of the class tests.
+        assert!(rendered.contains(
+            "def docstrings_Calculator_fixture():\n    Calculator.precision = 2\n    yield\n\n    \
+             Calculator.precision = None\n"
+        ));
+        assert!(rendered.contains(
+            "def test_docstrings_Calculator_add(docstrings_Calculator_fixture):\n    \
+             assert Calculator.add(1, 2) == 3"
+        ));
+
+        // Async functions are awaited within a coroutine run by asyncio.
+        assert!(rendered.contains(
+            "def test_docstrings_fetch(docstrings_fixture):\n    async def test():\n        \
+             assert await fetch(1) == 1\n\n    asyncio.run(test())"
+        ));
+    }
+
+    #[test]
+    fn process_objc_method() {
+        // Given
+        let mut method = Function::new("add:to:");
+        method.set_description(
+            "
+            # TESTCASE(Calculator::Add)
+                XCTAssertEqual([[Calculator new] add:1 to:2], 3);
+            ",
+        );
+
+        let mut class = Entity::with_kind("Calculator", EntityKind::Class);
+        class.add_entity::<Function>(EntityType::Function(method));
+
+        let mut index = Entity::new("");
+        index.add_entity::<Entity>(EntityType::Entity(class));
+        index.qualify();
+
+        let project_file = ProjectFile::new("calculator.m");
+        project_file.entities_mut().push(index);
+
+        let mut synthesis = synthesis("xctest");
+
+        // When
+        assert!(synthesis.process_testfile(&project_file).is_ok());
+
+        // Then
+        assert!(synthesis.stubs().generate_only);
+
+        let test_file = &synthesis.test_files[0];
+        assert_eq!(test_file.path, Path::new("test_calculator_m.m"));
+        assert!(
+            test_file.test_classes[0].starts_with("@interface CalculatorTests : XCTestCase")
+        );
+        assert!(
+            test_file.test_cases[0]
+                .content
+                .starts_with("@implementation CalculatorTests (add_to_)")
+        );
+        assert!(test_file.test_cases[0].content.contains("- (void)test_add_to_ {"));
+    }
+}
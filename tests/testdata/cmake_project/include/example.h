@@ -0,0 +1 @@
+int example(void);
@@ -0,0 +1,14 @@
+#ifndef ARGUMENTS_H_
+#define ARGUMENTS_H_
+
+namespace arguments {
+    /**
+     * # TESTCASE(arguments::ScaleByDefault)
+     *     # EQ[TL_FCT(value: 3) => 6]
+     */
+    unsigned int scale(unsigned int value, unsigned int factor = 2);
+
+    int sum(unsigned int count, ...);
+}  // namespace arguments
+
+#endif //ARGUMENTS_H_
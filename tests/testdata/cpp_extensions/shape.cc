@@ -0,0 +1,9 @@
+#include "shape.h"
+
+namespace shapes {
+
+int square::area() const {
+    return side * side;
+}
+
+} // namespace shapes
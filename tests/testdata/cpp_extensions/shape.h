@@ -0,0 +1,15 @@
+namespace shapes {
+
+class square {
+  public:
+    /**
+     * # TESTCASE(shapes::Area)
+     *     EXPECT_EQ(4, shapes::square(2).area());
+     */
+    int area() const;
+
+  private:
+    int side;
+};
+
+} // namespace shapes
@@ -0,0 +1,7 @@
+namespace shapes {
+
+inline int twice(int value) {
+    return 2 * value;
+}
+
+} // namespace shapes
@@ -0,0 +1,32 @@
+#ifndef SHAPES_H_
+#define SHAPES_H_
+
+namespace shapes {
+    class shape {
+        public:
+            virtual double area() const = 0;
+
+        protected:
+            unsigned int id() const;
+    };
+
+    class circle : public shape {
+        public:
+            double area() const;
+
+        private:
+            double radius() const;
+    };
+
+    struct point {
+        int x;
+        int y;
+    };
+
+    union value {
+        int i;
+        float f;
+    };
+}  // namespace shapes
+
+#endif //SHAPES_H_
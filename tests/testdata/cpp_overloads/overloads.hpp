@@ -0,0 +1,20 @@
+#ifndef OVERLOADS_H_
+#define OVERLOADS_H_
+
+namespace overloads {
+    /**
+     * # TESTCASE(overloads::AddInt)
+     *     # EQ[TL_FCT(no1: 1, no2: 2) => 3]
+     */
+    int add(int no1, int no2);
+
+    /**
+     * # TESTCASE(overloads::AddDouble)
+     *     double no2 = 2.5;
+     *     # EQ[TL_FCT(no1: 1.5, no2: no2) => 4.0]
+     *     # EQ[TL_FCT(no1: no2, no2: no2) => 5.0]
+     */
+    double add(double no1, double no2);
+}  // namespace overloads
+
+#endif //OVERLOADS_H_
@@ -0,0 +1,23 @@
+#ifndef QUALIFIERS_H_
+#define QUALIFIERS_H_
+
+namespace qualifiers {
+    class shape {
+        public:
+            shape() = default;
+            shape(const shape & other) = delete;
+            virtual ~shape() noexcept;
+
+            virtual unsigned int area() const = 0;
+            static constexpr unsigned int corners() { return 0; }
+            inline unsigned int scale(unsigned int factor) const { return factor; }
+    };
+
+    static unsigned int helper(unsigned int no1) {
+        return no1;
+    }
+
+    extern unsigned int exported(unsigned int no1);
+}  // namespace qualifiers
+
+#endif //QUALIFIERS_H_
@@ -0,0 +1,28 @@
+#ifndef TEMPLATES_H_
+#define TEMPLATES_H_
+
+namespace templates {
+    /**
+     * # TYPES(int, double)
+     * # TESTCASE(templates::Add)
+     *     EXPECT_EQ(TypeParam(3), templates::add<TypeParam>(1, 2));
+     */
+    template <typename T>
+    T add(T no1, T no2) {
+        return no1 + no2;
+    }
+
+    template <typename T, unsigned int N>
+    class buffer {
+        public:
+            unsigned int size() const;
+    };
+
+    template <typename T>
+    class buffer<T *, 0> {
+        public:
+            unsigned int size() const;
+    };
+}  // namespace templates
+
+#endif //TEMPLATES_H_
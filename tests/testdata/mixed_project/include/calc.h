@@ -0,0 +1 @@
+int add(int no1, int no2);
@@ -0,0 +1,5 @@
+namespace shapes {
+
+int area(int width, int height);
+
+}
@@ -0,0 +1,7 @@
+#include "calc.h"
+
+/// # TESTCASE(Calc::Add)
+///     EXPECT_EQ(3, add(1, 2));
+int add(int no1, int no2) {
+    return no1 + no2;
+}
@@ -0,0 +1,11 @@
+#include "shapes.hpp"
+
+namespace shapes {
+
+/// # TESTCASE(Shapes::Area)
+///     EXPECT_EQ(6, shapes::area(2, 3));
+int area(int width, int height) {
+    return width * height;
+}
+
+}
@@ -0,0 +1,12 @@
+/**
+ * #TESTCLASS(Counter)
+ */
+@interface Counter
+
+/**
+ * # TESTCASE(Counter::Increment)
+ *     XCTAssertEqual([Counter increment:1], 2);
+ */
++ (int)increment:(int)no;
+
+@end
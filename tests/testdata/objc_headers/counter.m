@@ -0,0 +1,9 @@
+#import "counter.h"
+
+@implementation Counter
+
++ (int)increment:(int)no {
+    return no + 1;
+}
+
+@end
@@ -0,0 +1,47 @@
+@interface Base
+@end
+
+/**
+ * #TESTCLASS(Calculator)
+ */
+@interface Calculator : Base
+
+/**
+ * # TESTCASE(Calculator::Add)
+ *     XCTAssertEqual([[Calculator new] add:1 to:2], 3);
+ */
+- (int)add:(int)no1 to:(int)no2;
+
+/**
+ * # TESTCASE(Calculator::Negate)
+ *     XCTAssertEqual([Calculator negate:2], -2);
+ */
++ (int)negate:(int)no;
+
+@end
+
+@interface Calculator (Scaling)
+
+- (double)scale:(double)value;
+
+@end
+
+@implementation Calculator
+
+- (int)add:(int)no1 to:(int)no2 {
+    return no1 + no2;
+}
+
++ (int)negate:(int)no {
+    return -no;
+}
+
+@end
+
+/**
+ * # TESTCASE(Calculator::Square)
+ *     XCTAssertEqual(square(3), 9);
+ */
+int square(int no) {
+    return no * no;
+}
@@ -0,0 +1,24 @@
+class Count {
+public:
+    int value = 0;
+};
+
+@interface Counter {
+    Count count;
+}
+
+/**
+ * # TESTCASE(Counter::Increment)
+ *     XCTAssertEqual([[Counter new] increment], 1);
+ */
+- (int)increment;
+
+@end
+
+@implementation Counter
+
+- (int)increment {
+    return ++count.value;
+}
+
+@end
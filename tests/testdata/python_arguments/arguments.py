@@ -0,0 +1,10 @@
+def scale(value, factor=2, *values, offset=0, **options):
+    """
+    # TESTCASE(scale_by_default)
+        # EQ[TL_FCT(value: 3) => 6]
+    """
+    return value * factor + offset
+
+
+def offset(value: int, factor: float = 1.5, *, by: int = 0) -> float:
+    return value * factor + by
@@ -0,0 +1,10 @@
+def add(no1, no2):
+    """
+    # TESTCASE(Broken::Add)
+        # EQ[TL_FCT(no1: 1, no2: 2) => 3]
+    """
+    return no1 + no2
+
+
+def sub(no1, no2)
+    return no1 - no2
@@ -0,0 +1,6 @@
+def mul(no1, no2):
+    """
+    # TESTCASE(Valid::Mul)
+        # EQ[TL_FCT(no1: 2, no2: 3) => 6]
+    """
+    return no1 * no2
@@ -0,0 +1,36 @@
+"""
+# SET_UP_CONTEXT:
+    import math
+"""
+
+
+class Calculator:
+    """
+    # TESTCLASS(Calculator)
+    # SET_UP_CONTEXT:
+        Calculator.precision = 2
+    # TEAR_DOWN_CONTEXT:
+        Calculator.precision = None
+    """
+
+    precision = None
+
+    @staticmethod
+    def add(no1, no2):
+        """
+        # TESTCASE(Calculator::Add)
+            assert Calculator.add(1, 2) == 3
+        """
+        return no1 + no2
+
+    @property
+    def name(self):
+        return "calculator"
+
+
+async def fetch(value):
+    """
+    # TESTCASE(fetch)
+        assert await fetch(1) == 1
+    """
+    return value
@@ -0,0 +1,15 @@
+def add(no1, no2):
+    """
+    # TESTCASE(Calc::Add)
+        assert add(1, 2) == 3
+    """
+    return no1 + no2
+
+
+class Calculator:
+    def mul(self, no1, no2):
+        """
+        # TESTCASE(Calc::Mul)
+            assert Calculator().mul(2, 3) == 6
+        """
+        return no1 * no2